@@ -0,0 +1,34 @@
+use const_it::{slice_eq, slice_fast_eq, SliceRef};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+const LEN: usize = 1 << 20;
+
+fn slice_eq(c: &mut Criterion) {
+    let a = vec![0u8; LEN];
+    let b = vec![0u8; LEN];
+    let shorter = vec![0u8; LEN - 1];
+
+    let mut group = c.benchmark_group("slice_eq");
+    group.bench_function("equal", |bench| {
+        bench.iter(|| slice_eq!(black_box(&a[..]), black_box(&b[..])))
+    });
+    group.bench_function("equal, word-wise", |bench| {
+        bench.iter(|| slice_fast_eq!(black_box(&a[..]), black_box(&b[..])))
+    });
+    group.bench_function("same slice", |bench| {
+        bench.iter(|| slice_eq!(black_box(&a[..]), black_box(&a[..])))
+    });
+    group.bench_function("same slice, PartialEq", |bench| {
+        bench.iter(|| SliceRef(black_box(&a[..])) == SliceRef(black_box(&a[..])))
+    });
+    group.bench_function("equal, PartialEq", |bench| {
+        bench.iter(|| SliceRef(black_box(&a[..])) == SliceRef(black_box(&b[..])))
+    });
+    group.bench_function("length mismatch", |bench| {
+        bench.iter(|| slice_eq!(black_box(&a[..]), black_box(&shorter[..])))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, slice_eq);
+criterion_main!(benches);
@@ -0,0 +1,261 @@
+use core::{cmp::Ordering, mem::MaybeUninit};
+
+/// Concatenate slices into an array. Panics if the total length of the slices isn't `N`.
+pub const fn concat<T: Copy, const N: usize>(parts: &[&[T]]) -> [T; N] {
+    let mut out = [MaybeUninit::<T>::uninit(); N];
+    let mut len = 0;
+    let mut i = 0;
+    while i < parts.len() {
+        let part = parts[i];
+        assert!(
+            part.len() <= N - len,
+            "total length of slices is longer than the output"
+        );
+        let mut j = 0;
+        while j < part.len() {
+            out[len] = MaybeUninit::new(part[j]);
+            len += 1;
+            j += 1;
+        }
+        i += 1;
+    }
+    assert!(
+        len == N,
+        "total length of slices is shorter than the output"
+    );
+    unsafe {
+        // safety: all N elements have been initialized above
+        *(&out as *const [MaybeUninit<T>; N] as *const [T; N])
+    }
+}
+
+/// Interleave the elements of two slices of equal length into an array, starting with the
+/// first element of `a`. Panics if the lengths differ or if `N` isn't twice the length.
+pub const fn interleave<T: Copy, const N: usize>(a: &[T], b: &[T]) -> [T; N] {
+    assert!(a.len() == b.len(), "slices must have the same length");
+    assert!(
+        a.len() * 2 == N,
+        "output length must be twice the length of the slices"
+    );
+    let mut out = [MaybeUninit::<T>::uninit(); N];
+    let mut i = 0;
+    while i < a.len() {
+        out[i * 2] = MaybeUninit::new(a[i]);
+        out[i * 2 + 1] = MaybeUninit::new(b[i]);
+        i += 1;
+    }
+    unsafe {
+        // safety: all N elements have been initialized above
+        *(&out as *const [MaybeUninit<T>; N] as *const [T; N])
+    }
+}
+
+/// Copy a slice to the start of an array, filling the rest of the array with `fill`.
+/// Panics if the slice is longer than `N`.
+pub const fn pad_end<T: Copy, const N: usize>(s: &[T], fill: T) -> [T; N] {
+    assert!(s.len() <= N, "slice is longer than the padded length");
+    let mut out = [fill; N];
+    let mut i = 0;
+    while i < s.len() {
+        out[i] = s[i];
+        i += 1;
+    }
+    out
+}
+
+/// Copy a slice to the end of an array, filling the rest of the array with `fill`.
+/// Panics if the slice is longer than `N`.
+pub const fn pad_start<T: Copy, const N: usize>(s: &[T], fill: T) -> [T; N] {
+    assert!(s.len() <= N, "slice is longer than the padded length");
+    let mut out = [fill; N];
+    let offset = N - s.len();
+    let mut i = 0;
+    while i < s.len() {
+        out[offset + i] = s[i];
+        i += 1;
+    }
+    out
+}
+
+/// Get a reference to the elements of an array from `start` to the end as an array of length
+/// `N`. Panics if `start` is out of range or if `N` isn't the number of remaining elements.
+pub const fn array_from<T, const M: usize, const N: usize>(a: &[T; M], start: usize) -> &[T; N] {
+    assert!(start <= M, "slice index out of range");
+    assert!(
+        M - start == N,
+        "output length doesn't match the remaining elements"
+    );
+    unsafe {
+        // safety: the remaining N elements are in bounds as checked above
+        &*(a.as_ptr().add(start) as *const [T; N])
+    }
+}
+
+/// Get references to the first `COUNT` chunks of `CHUNK` elements of a slice, or `None` if the
+/// slice is too short.
+pub const fn chunks_array<T, const CHUNK: usize, const COUNT: usize>(
+    s: &[T],
+) -> Option<[&[T; CHUNK]; COUNT]> {
+    match CHUNK.checked_mul(COUNT) {
+        Some(len) if len <= s.len() => (),
+        _ => return None,
+    }
+    let mut out = [MaybeUninit::<&[T; CHUNK]>::uninit(); COUNT];
+    let mut i = 0;
+    while i < COUNT {
+        out[i] = MaybeUninit::new(unsafe {
+            // safety: the slice has been checked to be long enough above
+            &*(s.as_ptr().add(i * CHUNK) as *const [T; CHUNK])
+        });
+        i += 1;
+    }
+    Some(unsafe {
+        // safety: all COUNT elements have been initialized above
+        *(&out as *const [MaybeUninit<&[T; CHUNK]>; COUNT] as *const [&[T; CHUNK]; COUNT])
+    })
+}
+
+/// Get references to the elements of a slice at each of the `indices`, or `None` if any of
+/// them are out of range.
+pub const fn get_many<T, const K: usize>(s: &[T], indices: [usize; K]) -> Option<[&T; K]> {
+    let mut out = [MaybeUninit::<&T>::uninit(); K];
+    let mut i = 0;
+    while i < K {
+        if indices[i] >= s.len() {
+            return None;
+        }
+        out[i] = MaybeUninit::new(&s[indices[i]]);
+        i += 1;
+    }
+    Some(unsafe {
+        // safety: all K elements have been initialized above
+        *(&out as *const [MaybeUninit<&T>; K] as *const [&T; K])
+    })
+}
+
+/// Element-wise operations on arrays of primitive integer types. The array can be
+/// wrapped either by value or by reference.
+pub struct ArrayMap<A>(pub A);
+
+macro_rules! impl_array_map {
+    ($($t:ty),* $(,)?) => { $(
+        impl<const N: usize> ArrayMap<[$t; N]> {
+            pub const fn add(self, n: $t) -> [$t; N] {
+                ArrayMap(&self.0).add(n)
+            }
+
+            pub const fn mul(self, n: $t) -> [$t; N] {
+                ArrayMap(&self.0).mul(n)
+            }
+        }
+
+        impl<'a, const N: usize> ArrayMap<&'a [$t; N]> {
+            pub const fn add(self, n: $t) -> [$t; N] {
+                let mut out = *self.0;
+                let mut i = 0;
+                while i < N {
+                    out[i] = out[i].wrapping_add(n);
+                    i += 1;
+                }
+                out
+            }
+
+            pub const fn mul(self, n: $t) -> [$t; N] {
+                let mut out = *self.0;
+                let mut i = 0;
+                while i < N {
+                    out[i] = out[i].wrapping_mul(n);
+                    i += 1;
+                }
+                out
+            }
+        }
+    )* };
+}
+
+impl_array_map!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl<const N: usize> ArrayMap<[u8; N]> {
+    pub const fn to_ascii_uppercase(self) -> [u8; N] {
+        ArrayMap(&self.0).to_ascii_uppercase()
+    }
+
+    pub const fn to_ascii_lowercase(self) -> [u8; N] {
+        ArrayMap(&self.0).to_ascii_lowercase()
+    }
+}
+
+impl<const N: usize> ArrayMap<&[u8; N]> {
+    pub const fn to_ascii_uppercase(self) -> [u8; N] {
+        let mut out = *self.0;
+        let mut i = 0;
+        while i < N {
+            out[i] = out[i].to_ascii_uppercase();
+            i += 1;
+        }
+        out
+    }
+
+    pub const fn to_ascii_lowercase(self) -> [u8; N] {
+        let mut out = *self.0;
+        let mut i = 0;
+        while i < N {
+            out[i] = out[i].to_ascii_lowercase();
+            i += 1;
+        }
+        out
+    }
+}
+
+macro_rules! impl_array_sort {
+    ($($t:ty),* $(,)?) => { $(
+        impl<const N: usize> ArrayMap<[$t; N]> {
+            pub const fn sort(self) -> [$t; N] {
+                ArrayMap(&self.0).sort()
+            }
+
+            pub const fn sort_desc(self) -> [$t; N] {
+                ArrayMap(&self.0).sort_desc()
+            }
+        }
+
+        impl<const N: usize> ArrayMap<&[$t; N]> {
+            const fn sort_by_ordering(self, reversed: bool) -> [$t; N] {
+                let mut out = *self.0;
+                let mut i = 1;
+                while i < N {
+                    let mut j = i;
+                    while j > 0 {
+                        let ordering = if out[j - 1] < out[j] {
+                            Ordering::Less
+                        } else if out[j - 1] > out[j] {
+                            Ordering::Greater
+                        } else {
+                            Ordering::Equal
+                        };
+                        let ordering = if reversed { ordering.reverse() } else { ordering };
+                        if !ordering.is_gt() {
+                            break;
+                        }
+                        let tmp = out[j - 1];
+                        out[j - 1] = out[j];
+                        out[j] = tmp;
+                        j -= 1;
+                    }
+                    i += 1;
+                }
+                out
+            }
+
+            pub const fn sort(self) -> [$t; N] {
+                self.sort_by_ordering(false)
+            }
+
+            pub const fn sort_desc(self) -> [$t; N] {
+                self.sort_by_ordering(true)
+            }
+        }
+    )* };
+}
+
+impl_array_sort!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, char, bool);
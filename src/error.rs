@@ -0,0 +1,103 @@
+use core::fmt;
+
+/// The reason a slice operation failed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SliceError {
+    /// The start of the range is higher than the end
+    StartGreaterThanEnd,
+
+    /// The index or range is out of range for the slice
+    OutOfRange,
+
+    /// The index or range would split a unicode codepoint in a `str`
+    SplitsCodepoint,
+}
+
+impl SliceError {
+    /// Get a description of the error
+    pub const fn message(self) -> &'static str {
+        match self {
+            Self::StartGreaterThanEnd => "slice index start is higher than end",
+            Self::OutOfRange => "slice index out of range",
+            Self::SplitsCodepoint => "slice splits utf-8 codepoint",
+        }
+    }
+}
+
+/// Panic with a message like "slice index start 4 is higher than end 3"
+#[track_caller]
+pub(crate) const fn panic_start_greater_than_end(start: i128, end: i128) -> ! {
+    let msg = start_greater_than_end_message(start, end);
+    panic!("{}", msg.as_str())
+}
+
+/// Build the message for [`panic_start_greater_than_end`]
+pub(crate) const fn start_greater_than_end_message(start: i128, end: i128) -> Message {
+    let mut msg = Message {
+        buf: [0; 128],
+        len: 0,
+    };
+    msg.push_str("slice index start ");
+    msg.push_int(start);
+    msg.push_str(" is higher than end ");
+    msg.push_int(end);
+    msg
+}
+
+/// A buffer for building a panic message in a const context
+pub(crate) struct Message {
+    buf: [u8; 128],
+    len: usize,
+}
+
+impl Message {
+    const fn push_str(&mut self, s: &str) {
+        let s = s.as_bytes();
+        let mut i = 0;
+        while i < s.len() {
+            self.buf[self.len] = s[i];
+            self.len += 1;
+            i += 1;
+        }
+    }
+
+    const fn push_int(&mut self, value: i128) {
+        if value < 0 {
+            self.push_str("-");
+        }
+        let mut digits = [0; 39];
+        let mut n = value.unsigned_abs();
+        let mut i = digits.len();
+        loop {
+            i -= 1;
+            digits[i] = b'0' + (n % 10) as u8;
+            n /= 10;
+            if n == 0 {
+                break;
+            }
+        }
+        while i < digits.len() {
+            self.buf[self.len] = digits[i];
+            self.len += 1;
+            i += 1;
+        }
+    }
+
+    pub(crate) const fn as_str(&self) -> &str {
+        let (bytes, _) = self.buf.split_at(self.len);
+        unsafe {
+            // safety: only ascii has been written to the buffer
+            core::str::from_utf8_unchecked(bytes)
+        }
+    }
+}
+
+impl fmt::Display for SliceError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SliceError {}
@@ -0,0 +1,196 @@
+use core::str;
+
+/// An iterator over the non-empty substrings of a `str` separated by ASCII whitespace.
+/// Use the const [`next`](ConstSplitWhitespace::next) method to iterate in a const context.
+///
+/// This is usually created with the [`str_split_whitespace!`] macro.
+#[derive(Clone, Debug)]
+pub struct ConstSplitWhitespace<'a> {
+    rest: &'a str,
+}
+
+impl<'a> ConstSplitWhitespace<'a> {
+    /// Create a new iterator over the whitespace separated parts of `s`
+    pub const fn new(s: &'a str) -> Self {
+        Self { rest: s }
+    }
+
+    /// Get the next part, or `None` if there are no more parts
+    #[allow(clippy::should_implement_trait)]
+    pub const fn next(&mut self) -> Option<&'a str> {
+        let bytes = self.rest.as_bytes();
+        let mut start = 0;
+        while start < bytes.len() && bytes[start].is_ascii_whitespace() {
+            start += 1;
+        }
+        if start == bytes.len() {
+            self.rest = "";
+            return None;
+        }
+        let mut end = start;
+        while end < bytes.len() && !bytes[end].is_ascii_whitespace() {
+            end += 1;
+        }
+        let (head, rest) = bytes.split_at(end);
+        let (_, part) = head.split_at(start);
+        unsafe {
+            // safety: ascii whitespace is always on a codepoint boundary
+            self.rest = str::from_utf8_unchecked(rest);
+            Some(str::from_utf8_unchecked(part))
+        }
+    }
+}
+
+impl<'a> Iterator for ConstSplitWhitespace<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        ConstSplitWhitespace::next(self)
+    }
+}
+
+/// An iterator over the lines of a `str` including their line endings, like
+/// `str::split_inclusive('\n')`. A final line without a line ending is also included.
+/// Use the const [`next`](ConstLinesInclusive::next) method to iterate in a const context.
+///
+/// This is usually created with the [`str_lines_inclusive!`] macro.
+#[derive(Clone, Debug)]
+pub struct ConstLinesInclusive<'a> {
+    rest: &'a str,
+}
+
+impl<'a> ConstLinesInclusive<'a> {
+    /// Create a new iterator over the lines of `s`
+    pub const fn new(s: &'a str) -> Self {
+        Self { rest: s }
+    }
+
+    /// Get the next line including its line ending, or `None` if there are no more lines
+    #[allow(clippy::should_implement_trait)]
+    pub const fn next(&mut self) -> Option<&'a str> {
+        let bytes = self.rest.as_bytes();
+        if bytes.is_empty() {
+            return None;
+        }
+        let mut end = 0;
+        while end < bytes.len() && bytes[end] != b'\n' {
+            end += 1;
+        }
+        if end < bytes.len() {
+            end += 1;
+        }
+        let (line, rest) = bytes.split_at(end);
+        unsafe {
+            // safety: line breaks are always on a codepoint boundary
+            self.rest = str::from_utf8_unchecked(rest);
+            Some(str::from_utf8_unchecked(line))
+        }
+    }
+}
+
+impl<'a> Iterator for ConstLinesInclusive<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        ConstLinesInclusive::next(self)
+    }
+}
+
+/// An iterator over fixed size chunks of a slice as array references, starting from the end.
+/// If the length of the slice isn't a multiple of `N`, the leading elements that don't fit in
+/// a chunk are left out and can be retrieved with [`remainder`](ConstRArrayChunks::remainder).
+/// Use the const [`next`](ConstRArrayChunks::next) method to iterate in a const context.
+///
+/// This is usually created with the [`slice_rchunks_array!`] macro.
+#[derive(Clone, Debug)]
+pub struct ConstRArrayChunks<'a, T, const N: usize> {
+    chunks: &'a [T],
+    remainder: &'a [T],
+}
+
+impl<'a, T, const N: usize> ConstRArrayChunks<'a, T, N> {
+    /// Create a new iterator over the chunks of `s`. Panics if `N` is zero.
+    pub const fn new(s: &'a [T]) -> Self {
+        assert!(N != 0, "chunk size must be non-zero");
+        let (remainder, chunks) = s.split_at(s.len() % N);
+        Self { chunks, remainder }
+    }
+
+    /// Get the leading elements that don't fit in a chunk
+    pub const fn remainder(&self) -> &'a [T] {
+        self.remainder
+    }
+
+    /// Get the next chunk from the end, or `None` if there are no more chunks
+    #[allow(clippy::should_implement_trait)]
+    pub const fn next(&mut self) -> Option<&'a [T; N]> {
+        if self.chunks.is_empty() {
+            return None;
+        }
+        let (rest, chunk) = self.chunks.split_at(self.chunks.len() - N);
+        self.chunks = rest;
+        Some(unsafe {
+            // safety: the length of `chunks` is always a multiple of N
+            &*(chunk.as_ptr() as *const [T; N])
+        })
+    }
+}
+
+impl<'a, T, const N: usize> Iterator for ConstRArrayChunks<'a, T, N> {
+    type Item = &'a [T; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        ConstRArrayChunks::next(self)
+    }
+}
+
+/// An iterator over chunks of exactly `n` elements of a slice, starting from the beginning,
+/// like `slice::chunks_exact`. If the length of the slice isn't a multiple of `n`, the trailing
+/// elements that don't fit in a chunk are left out and can be retrieved with
+/// [`remainder`](ConstChunksExact::remainder). Use the const [`next`](ConstChunksExact::next)
+/// method to iterate in a const context.
+///
+/// This is usually created with the [`slice_chunks_exact!`] macro.
+#[derive(Clone, Debug)]
+pub struct ConstChunksExact<'a, T> {
+    chunks: &'a [T],
+    remainder: &'a [T],
+    n: usize,
+}
+
+impl<'a, T> ConstChunksExact<'a, T> {
+    /// Create a new iterator over the chunks of `n` elements of `s`. Panics if `n` is zero.
+    pub const fn new(s: &'a [T], n: usize) -> Self {
+        assert!(n != 0, "chunk size must be non-zero");
+        let (chunks, remainder) = s.split_at(s.len() - s.len() % n);
+        Self {
+            chunks,
+            remainder,
+            n,
+        }
+    }
+
+    /// Get the trailing elements that don't fit in a chunk
+    pub const fn remainder(&self) -> &'a [T] {
+        self.remainder
+    }
+
+    /// Get the next chunk, or `None` if there are no more chunks
+    #[allow(clippy::should_implement_trait)]
+    pub const fn next(&mut self) -> Option<&'a [T]> {
+        if self.chunks.is_empty() {
+            return None;
+        }
+        let (chunk, rest) = self.chunks.split_at(self.n);
+        self.chunks = rest;
+        Some(chunk)
+    }
+}
+
+impl<'a, T> Iterator for ConstChunksExact<'a, T> {
+    type Item = &'a [T];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        ConstChunksExact::next(self)
+    }
+}
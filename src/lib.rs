@@ -15,15 +15,27 @@
 //! const STR: (&str, &str) = slice_split_at!("const slice", 5); // ("const", " slice")
 //! ```
 //!
+//! [`slice_split_around!`] and [`slice_try_split_around!`] split a slice in three around a range.
+//!
 //! The [`slice_cmp!`] and [`slice_eq!`] macros compare slices. [`slice_starts_with!`] and
 //! [`slice_strip_prefix!`] checks for and strips a prefix, respectively, and
 //! [`slice_ends_with!`] and [`slice_strip_suffix!`] do the same for suffixes.
 //!
+//! The [`ops`] module has `const fn` versions of some of these macros for byte slices and `str`s.
+//!
+//! [`SliceRef`] wraps a slice or `str` reference with `Eq` and `Ord` implementations, so it can
+//! be sorted or used as a key in ordered collections.
+//!
 //! The [`ok!`], [`expect_ok!`], [`unwrap_ok!`], [`unwrap_ok_or_return!`], [`expect_some!`], [`unwrap_some!`]
 //! and [`unwrap_some_or_return!`] macros work with `Result`s and `Option`s.
+//!
+//! Enable the `std` feature to implement `std::error::Error` for [`SliceError`].
 
 #![no_std]
 
+#[cfg(feature = "std")]
+extern crate std;
+
 /// Turn a `Result` into an `Option`.
 #[macro_export]
 macro_rules! ok {
@@ -37,10 +49,16 @@ macro_rules! ok {
 
 /// Slice an item in a const context. The first argument is the item to slice, and
 /// the second is the slice index, which can be a usize or any usize range type.
+/// Ranges can also be passed by reference, and ranges of other integer types can be
+/// passed with [`IntRange`]. A `(start, end)` tuple of `usize`s is the same as `start..end`,
+/// and a `str` can be sliced by `char` positions with [`Chars`].
 /// Panics if the index is out of range or, for strings, if the slice would split a
-/// unicode codepoint.
+/// unicode codepoint. Panics at runtime are reported at the location of the `slice!` call.
+/// At runtime, the item can also be a `&mut` slice or array, which is reborrowed immutably
+/// for the lifetime of the result.
 ///
-/// Alternately use [`try_slice!`] to get an `Option` instead of panicing.
+/// Alternately use [`try_slice!`] to get an `Option` instead of panicing. To slice the same
+/// item several times, you can also use [`Slice::new`].
 ///
 /// ```rust
 /// # use { const_it::slice, core::ops::Range };
@@ -59,10 +77,15 @@ macro_rules! slice {
 
 /// Slice an item in a const context. The first argument is the item to slice, and
 /// the second is the slice index, which can be a usize or any usize range type.
+/// Ranges can also be passed by reference, and ranges of other integer types can be
+/// passed with [`IntRange`]. A `(start, end)` tuple of `usize`s is the same as `start..end`,
+/// and a `str` can be sliced by `char` positions with [`Chars`].
 /// Returns `Some(sliced)`, or `None` if the index is out of range or, for strings,
-/// if the slice would split a unicode codepoint.
+/// if the slice would split a unicode codepoint. The index can also be an `Option` of a
+/// usize range type, which returns `None` if the index is `None`.
 ///
-/// Alternately use [`slice!`] if you want to panic on error instead.
+/// Alternately use [`slice!`] if you want to panic on error instead, or [`slice_try_result!`]
+/// to get the reason for the error.
 ///
 /// ```rust
 /// # use { const_it::{try_slice, unwrap_some}, core::ops::Range };
@@ -71,6 +94,7 @@ macro_rules! slice {
 /// const BYTES2: &[u8] = unwrap_some!(BYTES); // b"123"
 /// const RANGE: Range<usize> = (BYTES2[0] - b'0') as usize..(BYTES2[2] - b'0') as usize;
 /// const STR2: Option<&str> = try_slice!(unwrap_some!(STR), RANGE); // Some("on")
+/// const NONE: Option<&str> = try_slice!("const slice", None::<Range<usize>>); // None
 /// ```
 #[macro_export]
 macro_rules! try_slice {
@@ -80,6 +104,137 @@ macro_rules! try_slice {
     }};
 }
 
+/// Slice an item in a const context, like [`try_slice!`], but returning
+/// `Result<&Output, SliceError>` with the reason for the error instead of an `Option`.
+///
+/// ```rust
+/// # use const_it::{slice_try_result, SliceError};
+/// const STR: Result<&str, SliceError> = slice_try_result!("const slice", ..5); // Ok("const")
+/// const ERR: Result<&str, SliceError> = slice_try_result!("✨", 1..); // Err(SliceError::SplitsCodepoint)
+/// ```
+#[macro_export]
+macro_rules! slice_try_result {
+    ($slicable:expr, $index:expr) => {{
+        let _ = $crate::__internal::SliceTypeCheck($slicable, $index);
+        $crate::__internal::Slice($slicable, $index).checked_get()
+    }};
+}
+
+/// Slice an item without checking that the range is valid. The first argument is the item to
+/// slice, and the second is the slice index, which can be any usize range type.
+///
+/// This expands to a call to an unsafe function, so it must be used in an `unsafe` block.
+/// Bounds are only checked with debug assertions.
+///
+/// # Safety
+/// The range must be in bounds for the item, i.e. its start must not be greater than its end,
+/// and its end must not be greater than the length. For strings, the start and end of the range
+/// must also be on codepoint boundaries. Violating this is undefined behaviour.
+///
+/// ```rust
+/// # use const_it::slice_index_unchecked;
+/// const STR: &str = unsafe { slice_index_unchecked!("const slice", ..5) }; // "const"
+/// ```
+#[macro_export]
+macro_rules! slice_index_unchecked {
+    ($slicable:expr, $index:expr) => {{
+        let _ = $crate::__internal::SliceTypeCheck($slicable, $index);
+        $crate::__internal::Slice($slicable, $index).index_unchecked()
+    }};
+}
+
+/// Slice an item in a const context, clamping the range to the bounds of the item instead of
+/// failing. The first argument is the item to slice, and the second is the slice index, which
+/// can be any usize range type. Indices past the end are clamped to the length, and if the
+/// start is after the end, the result is empty. For strings, indices inside a unicode
+/// codepoint are moved inwards to the nearest codepoint boundary.
+///
+/// ```rust
+/// # use const_it::slice_clamped;
+/// const STR: &str = slice_clamped!("const slice", 6..100); // "slice"
+/// const EMPTY: &[u8] = slice_clamped!(b"01234", 4..2); // b""
+/// ```
+#[macro_export]
+macro_rules! slice_clamped {
+    ($slicable:expr, $index:expr) => {{
+        let _ = $crate::__internal::SliceTypeCheck($slicable, $index);
+        $crate::__internal::Slice($slicable, $index).clamped()
+    }};
+}
+
+/// Get the first `n` elements of a slice, or the whole slice if it's shorter than `n`. Unlike
+/// `slice!(s, ..n)`, this never panics. For strings, `n` is in bytes, and if it's inside a
+/// unicode codepoint, the prefix ends before that codepoint. This is the same as
+/// [`slice_clamped!`] with a `..n` range.
+///
+/// ```rust
+/// # use const_it::slice_prefix;
+/// const BYTES: &[u8] = slice_prefix!(b"01234", 3); // b"012"
+/// const STR: &str = slice_prefix!("const", 100); // "const"
+/// ```
+///
+/// See also [`str_truncate_to!`].
+#[macro_export]
+macro_rules! slice_prefix {
+    ($slicable:expr, $n:expr) => {{
+        let n: ::core::primitive::usize = $n;
+        $crate::slice_clamped!($slicable, ..n)
+    }};
+}
+
+/// Get a copy of the element at the specified `usize` index of a slice. Panics if the index
+/// is out of range. Unlike [`slice!`], this returns the element by value, so the result doesn't
+/// borrow from the slice.
+///
+/// ```rust
+/// # use const_it::slice_copy;
+/// let value: u8 = slice_copy!(&[1, 2, 4], 2); // 4
+/// ```
+///
+/// See also [`slice_try_copy!`].
+#[macro_export]
+macro_rules! slice_copy {
+    ($slicable:expr, $index:expr) => {{
+        let _: ::core::primitive::usize = $index;
+        $crate::__internal::Slice($slicable, $index).copy()
+    }};
+}
+
+/// Get a copy of the element at the specified `usize` index of a slice, or `None` if the index
+/// is out of range. Unlike [`try_slice!`], this returns the element by value, so the result
+/// doesn't borrow from the slice.
+///
+/// ```rust
+/// # use const_it::slice_try_copy;
+/// let value: Option<u8> = slice_try_copy!(&[1, 2, 4], 3); // None
+/// ```
+///
+/// See also [`slice_copy!`].
+#[macro_export]
+macro_rules! slice_try_copy {
+    ($slicable:expr, $index:expr) => {{
+        let _: ::core::primitive::usize = $index;
+        $crate::__internal::Slice($slicable, $index).try_copy()
+    }};
+}
+
+/// Get a copy of the element at the specified `usize` index of a slice, or the default
+/// value given as the third argument if the index is out of range.
+///
+/// ```rust
+/// # use const_it::slice_get_or;
+/// const TABLE: [u8; 3] = [1, 2, 4];
+/// const IN_RANGE: u8 = slice_get_or!(&TABLE, 2, 0); // 4
+/// const OUT_OF_RANGE: u8 = slice_get_or!(&TABLE, 3, 0); // 0
+/// ```
+#[macro_export]
+macro_rules! slice_get_or {
+    ($slicable:expr, $index:expr, $default:expr) => {{
+        let _: ::core::primitive::usize = $index;
+        $crate::__internal::Slice($slicable, $index).get_or($default)
+    }};
+}
+
 /// Split a slice in two at the specified index. Panics on error.
 ///
 /// See also [`slice_try_split_at!`].
@@ -102,6 +257,144 @@ macro_rules! slice_try_split_at {
     }};
 }
 
+/// Split a slice in two at a fraction of its length. The second and third arguments are the
+/// numerator and the denominator of the fraction, and the slice is split at index
+/// `len * num / den`, rounded down. Panics (or fails to compile, in a const context) if the
+/// denominator is zero, if the fraction is greater than one or, for `str`, if the index would
+/// split a unicode codepoint.
+///
+/// ```rust
+/// # use const_it::slice_split_at_ratio;
+/// const PARTS: (&[u8], &[u8]) = slice_split_at_ratio!(b"0123456789", 3, 10); // (b"012", b"3456789")
+/// ```
+#[macro_export]
+macro_rules! slice_split_at_ratio {
+    ($slicable:expr, $num:expr, $den:expr) => {{
+        let slicable = $slicable;
+        let len = $crate::__internal::SliceRef(slicable).as_slice().0.len();
+        $crate::slice_split_at!(slicable, $crate::__internal::ratio_index(len, $num, $den))
+    }};
+}
+
+/// Split a slice in two at the specified index. Returns `None` if the index is greater than
+/// the length of the slice or, for `str`, if it's inside a unicode codepoint. Splitting at `0`
+/// or at the length of the slice always succeeds. This is named after `split_at_checked` in
+/// `core`, and behaves the same as [`slice_try_split_at!`].
+///
+/// ```rust
+/// # use const_it::slice_split_at_checked;
+/// const SPLIT: Option<(&str, &str)> = slice_split_at_checked!("const", 5); // Some(("const", ""))
+/// ```
+///
+/// See also [`slice_checked_split_at!`].
+#[macro_export]
+macro_rules! slice_split_at_checked {
+    ($slicable:expr, $index:expr) => {
+        $crate::slice_try_split_at!($slicable, $index)
+    };
+}
+
+/// Split a slice in two at the specified index. Returns a `Result` with the reason for the
+/// error on failure, so for `str` you can tell [`SliceError::OutOfRange`] apart from
+/// [`SliceError::SplitsCodepoint`].
+///
+/// ```rust
+/// # use const_it::{slice_checked_split_at, SliceError};
+/// const SPLIT: Result<(&str, &str), SliceError> = slice_checked_split_at!("✨💖", 3); // Ok(("✨", "💖"))
+/// const ERROR: Result<(&str, &str), SliceError> = slice_checked_split_at!("✨💖", 2); // Err(SliceError::SplitsCodepoint)
+/// ```
+///
+/// See also [`slice_split_at!`] and [`slice_try_split_at!`].
+#[macro_export]
+macro_rules! slice_checked_split_at {
+    ($slicable:expr, $index:expr) => {{
+        let _: ::core::primitive::usize = $index;
+        $crate::__internal::Slice($slicable, $index).checked_split()
+    }};
+}
+
+/// Split a slice at several ascending indices, returning a tuple of the parts. Up to four
+/// indices are supported. Panics if the indices aren't ascending or are out of range.
+///
+/// ```rust
+/// # use const_it::slice_split_at_many;
+/// const PARTS: (&[u8], &[u8], &[u8]) = slice_split_at_many!(b"aabbbcc", [2, 5]); // (b"aa", b"bbb", b"cc")
+/// ```
+#[macro_export]
+macro_rules! slice_split_at_many {
+    ($slicable:expr, [$i0:expr $(,)?]) => {
+        $crate::slice_split_at!($slicable, $i0)
+    };
+
+    ($slicable:expr, [$i0:expr, $i1:expr $(,)?]) => {{
+        let (i0, i1): (::core::primitive::usize, ::core::primitive::usize) = ($i0, $i1);
+        ::core::assert!(i0 <= i1, "split indices must be ascending");
+        let (s0, rest) = $crate::slice_split_at!($slicable, i0);
+        let (s1, s2) = $crate::slice_split_at!(rest, i1 - i0);
+        (s0, s1, s2)
+    }};
+
+    ($slicable:expr, [$i0:expr, $i1:expr, $i2:expr $(,)?]) => {{
+        let (i0, i1, i2): (
+            ::core::primitive::usize,
+            ::core::primitive::usize,
+            ::core::primitive::usize,
+        ) = ($i0, $i1, $i2);
+        ::core::assert!(i0 <= i1 && i1 <= i2, "split indices must be ascending");
+        let (s0, rest) = $crate::slice_split_at!($slicable, i0);
+        let (s1, rest) = $crate::slice_split_at!(rest, i1 - i0);
+        let (s2, s3) = $crate::slice_split_at!(rest, i2 - i1);
+        (s0, s1, s2, s3)
+    }};
+
+    ($slicable:expr, [$i0:expr, $i1:expr, $i2:expr, $i3:expr $(,)?]) => {{
+        let (i0, i1, i2, i3): (
+            ::core::primitive::usize,
+            ::core::primitive::usize,
+            ::core::primitive::usize,
+            ::core::primitive::usize,
+        ) = ($i0, $i1, $i2, $i3);
+        ::core::assert!(
+            i0 <= i1 && i1 <= i2 && i2 <= i3,
+            "split indices must be ascending"
+        );
+        let (s0, rest) = $crate::slice_split_at!($slicable, i0);
+        let (s1, rest) = $crate::slice_split_at!(rest, i1 - i0);
+        let (s2, rest) = $crate::slice_split_at!(rest, i2 - i1);
+        let (s3, s4) = $crate::slice_split_at!(rest, i3 - i2);
+        (s0, s1, s2, s3, s4)
+    }};
+}
+
+/// Split a slice in three around the specified `Range<usize>`, returning the parts
+/// before, within and after the range. Panics on error.
+///
+/// ```rust
+/// # use const_it::slice_split_around;
+/// const PARTS: (&[u8], &[u8], &[u8]) = slice_split_around!(b"abcde", 1..3); // (b"a", b"bc", b"de")
+/// ```
+///
+/// See also [`slice_try_split_around!`].
+#[macro_export]
+macro_rules! slice_split_around {
+    ($slicable:expr, $range:expr) => {{
+        let _: ::core::ops::Range<::core::primitive::usize> = $range;
+        $crate::__internal::Slice($slicable, $range).split_around()
+    }};
+}
+
+/// Split a slice in three around the specified `Range<usize>`, returning the parts
+/// before, within and after the range. Returns `None` on error.
+///
+/// See also [`slice_split_around!`].
+#[macro_export]
+macro_rules! slice_try_split_around {
+    ($slicable:expr, $range:expr) => {{
+        let _: ::core::ops::Range<::core::primitive::usize> = $range;
+        $crate::__internal::Slice($slicable, $range).try_split_around()
+    }};
+}
+
 #[doc(hidden)]
 #[deprecated = "renamed to slice_split_at"]
 #[macro_export]
@@ -120,181 +413,1755 @@ macro_rules! try_split_slice_at {
     }};
 }
 
-/// Compare two slices, returning an `Ordering`. This only works for slices of primitive integer types and `str`.
+/// Concatenate a list of slices into an array. The slices must be constants, as the
+/// length of the output array is computed at compile time.
+///
+/// ```rust
+/// # use const_it::slice_concat_many;
+/// const HELLO: &[u8] = b"hello";
+/// const BYTES: [u8; 11] = slice_concat_many!([HELLO, b" ", b"world"]); // *b"hello world"
+/// ```
 #[macro_export]
-macro_rules! slice_cmp {
-    ($a:expr, $b:expr) => {
-        $crate::__internal::SliceRef($a).cmp($crate::__internal::SliceRef($b))
-    };
+macro_rules! slice_concat_many {
+    ([$($slice:expr),+ $(,)?]) => {{
+        const LEN: ::core::primitive::usize = 0 $(+ $slice.len())+;
+        $crate::__internal::concat::<_, LEN>(&[$($slice),+])
+    }};
 }
 
-/// Compare two slices, returning an `Option<Ordering>`. Currently all supported types always return `Some`.
-/// This only works for slices of primitive integer types and `str`.
+/// Interleave the elements of two slices of equal length into an array, alternating between
+/// the first and the second slice. The slices must be constants, as the length of the output
+/// array is computed at compile time. Panics if the lengths of the slices differ.
+///
+/// ```rust
+/// # use const_it::slice_interleave;
+/// const ARRAY: [i32; 6] = slice_interleave!(&[1, 2, 3], &[4, 5, 6]); // [1, 4, 2, 5, 3, 6]
+/// ```
 #[macro_export]
-macro_rules! slice_partial_cmp {
-    ($a:expr, $b:expr) => {
-        $crate::__internal::SliceRef($a).partial_cmp($crate::__internal::SliceRef($b))
-    };
+macro_rules! slice_interleave {
+    ($a:expr, $b:expr) => {{
+        const LEN: ::core::primitive::usize = $a.len() * 2;
+        $crate::__internal::interleave::<_, LEN>($a, $b)
+    }};
 }
 
-/// Check if two slices are equal. This only works for slices of primitive integer types and `str`.
+/// Pad a slice to a fixed length by appending copies of a fill value, returning an array.
+/// The length must be a constant. Panics (or fails to compile, in a const context) if the
+/// slice is longer than the length.
+///
+/// ```rust
+/// # use const_it::slice_pad_end;
+/// const PADDED: [u8; 4] = slice_pad_end!(b"ab", 4, b' '); // *b"ab  "
+/// ```
+///
+/// See also [`slice_pad_start!`].
 #[macro_export]
-macro_rules! slice_eq {
-    ($a:expr, $b:expr) => {
-        ::core::matches!(
-            $crate::slice_partial_cmp!($a, $b),
-            ::core::option::Option::Some(::core::cmp::Ordering::Equal)
-        )
+macro_rules! slice_pad_end {
+    ($slice:expr, $len:expr, $fill:expr) => {
+        $crate::__internal::pad_end::<_, { $len }>($slice, $fill)
     };
 }
 
-/// Check if a slice starts with another slice. This only works for slices of primitive integer types and `str`.
+/// Pad a slice to a fixed length by prepending copies of a fill value, returning an array.
+/// The length must be a constant. Panics (or fails to compile, in a const context) if the
+/// slice is longer than the length.
+///
+/// ```rust
+/// # use const_it::slice_pad_start;
+/// const PADDED: [u8; 4] = slice_pad_start!(b"12", 4, b'0'); // *b"0012"
+/// ```
+///
+/// See also [`slice_pad_end!`].
 #[macro_export]
-macro_rules! slice_starts_with {
-    ($s:expr, $prefix:expr) => {
-        $crate::slice_strip_prefix!($s, $prefix).is_some()
+macro_rules! slice_pad_start {
+    ($slice:expr, $len:expr, $fill:expr) => {
+        $crate::__internal::pad_start::<_, { $len }>($slice, $fill)
     };
 }
 
-/// Check if a slice ends with another slice. This only works for slices of primitive integer types and `str`.
+/// Get references to the elements of a slice at a list of `usize` indices, returning
+/// `Option<[&T; K]>`, or `None` if any of the indices are out of range. The indices don't
+/// have to be distinct or in order.
+///
+/// ```rust
+/// # use const_it::slice_get_many;
+/// const ELEMENTS: Option<[&i32; 2]> = slice_get_many!(&[10, 20, 30], [0, 2]); // Some([&10, &30])
+/// ```
 #[macro_export]
-macro_rules! slice_ends_with {
-    ($s:expr, $prefix:expr) => {
-        $crate::slice_strip_suffix!($s, $prefix).is_some()
+macro_rules! slice_get_many {
+    ($slice:expr, [$($index:expr),+ $(,)?]) => {
+        $crate::__internal::get_many($slice, [$($index),+])
     };
 }
 
-/// Strip a prefix from a slice, returning an Option with the stripped slice on success. This only works for slices of primitive integer types and `str`.
+/// Get a reference to the elements of an array from an index to the end, like `slice!(array, start..)`,
+/// but returning an array reference that keeps the length in its type. The first argument must be
+/// a reference to an array and the start index must be a constant. Fails to compile if the start
+/// index is out of range.
+///
+/// ```rust
+/// # use const_it::array_from;
+/// const ARRAY: [u8; 8] = *b"\x89PNG\r\n\x1a\n";
+/// const REST: &[u8; 6] = array_from!(&ARRAY, 2); // b"NG\r\n\x1a\n"
+/// ```
 #[macro_export]
-macro_rules! slice_strip_prefix {
-    ($s:expr, $prefix:expr) => {{
-        let (slice, prefix) = (
-            $crate::__internal::SliceRef($s),
-            $crate::__internal::SliceRef($prefix),
-        );
-        if slice.len() >= prefix.len() {
-            let (pfx, rest) = $crate::slice_split_at!(slice.0, prefix.len());
-            if $crate::slice_eq!(pfx, prefix.0) {
-                Some(rest)
-            } else {
-                None
-            }
-        } else {
-            None
-        }
+macro_rules! array_from {
+    ($array:expr, $start:expr) => {{
+        const LEN: ::core::primitive::usize = $array.len() - $start;
+        $crate::__internal::array_from::<_, _, LEN>($array, $start)
     }};
 }
 
-/// Strip a suffix from a slice, returning an Option with the stripped slice on success. This only works for slices of primitive integer types and `str`.
+/// Get references to a fixed number of fixed size chunks from the start of a slice, returning
+/// `Option<[&[T; CHUNK]; COUNT]>`, or `None` if the slice is shorter than `CHUNK * COUNT`.
+/// The second argument is the chunk size and the third is the number of chunks, and both must
+/// be constants. Any elements after the chunks are ignored.
+///
+/// ```rust
+/// # use const_it::slice_chunks_array;
+/// const CHUNKS: Option<[&[i32; 2]; 2]> = slice_chunks_array!(&[1, 2, 3, 4, 5], 2, 2); // Some([&[1, 2], &[3, 4]])
+/// ```
 #[macro_export]
-macro_rules! slice_strip_suffix {
-    ($s:expr, $suffix:expr) => {{
-        let (slice, suffix) = (
-            $crate::__internal::SliceRef($s),
-            $crate::__internal::SliceRef($suffix),
-        );
-        if slice.len() >= suffix.len() {
-            let (rest, suff) = $crate::slice_split_at!(slice.0, slice.len() - suffix.len());
-            if $crate::slice_eq!(suff, suffix.0) {
-                Some(rest)
-            } else {
-                None
-            }
-        } else {
-            None
-        }
-    }};
+macro_rules! slice_chunks_array {
+    ($slice:expr, $chunk:expr, $count:expr) => {
+        $crate::__internal::chunks_array::<_, { $chunk }, { $count }>($slice)
+    };
 }
 
-/// Takes a `Result` and returns the unwrapped `Ok` value, or panics if it's `Err`.
-/// The second argument is the message to use on panic. If the panic message
-/// is omitted, the `Err` value must be of type `&str` and is used as the panic message.
+/// Create a [`ConstRArrayChunks`] iterator over chunks of a slice as `&[T; N]` array references,
+/// starting from the end. The chunk size must be a constant. Any leading elements that don't
+/// fit in a chunk are available from [`ConstRArrayChunks::remainder`].
 ///
-/// See also [`expect_some!`] and [`unwrap_ok!`].
+/// ```rust
+/// # use const_it::slice_rchunks_array;
+/// const fn parse_groups(digits: &[u8]) -> usize {
+///     let mut chunks = slice_rchunks_array!(digits, 3);
+///     let mut groups = 0;
+///     while chunks.next().is_some() {
+///         groups += 1;
+///     }
+///     groups
+/// }
+/// const GROUPS: usize = parse_groups(b"1234567"); // 2
+/// ```
 #[macro_export]
-macro_rules! expect_ok {
-    ($expr:expr) => {
-        match $expr {
-            ::core::result::Result::Ok(value) => value,
-            ::core::result::Result::Err(err) => panic!("{}", err),
-        }
-    };
-
-    ($expr:expr, $message:expr) => {
-        match $expr {
-            ::core::result::Result::Ok(value) => value,
-            ::core::result::Result::Err(_) => panic!("{}", $message),
-        }
+macro_rules! slice_rchunks_array {
+    ($slice:expr, $n:expr) => {
+        $crate::ConstRArrayChunks::<_, { $n }>::new($slice)
     };
 }
 
-/// Takes an `Option` and returns the unwrapped `Some` value, or panics if it's `None`.
-/// The second argument is the message to use on panic.
+/// Get the last `n` elements of a slice as a reference to an array, like
+/// `slice::last_chunk`. Returns `Option<&[T; n]>`, or `None` if the slice is shorter than `n`.
+/// The length must be a constant.
 ///
-/// See also [`expect_ok!`] and [`unwrap_some!`].
+/// ```rust
+/// # use const_it::slice_last_chunk_array;
+/// const PACKET: &[u8] = b"\x01\x02payload\xde\xad\xbe\xef";
+/// const CHECKSUM: Option<&[u8; 4]> = slice_last_chunk_array!(PACKET, 4); // Some(b"\xde\xad\xbe\xef")
+/// ```
+///
+/// See also [`slice_rchunks_array!`].
 #[macro_export]
-macro_rules! expect_some {
-    ($expr:expr, $message:expr) => {
-        match $expr {
-            ::core::option::Option::Some(value) => value,
-            ::core::option::Option::None => panic!("{}", $message),
-        }
-    };
+macro_rules! slice_last_chunk_array {
+    ($slice:expr, $n:expr) => {{
+        let slice: &[_] = $slice;
+        slice.last_chunk::<{ $n }>()
+    }};
 }
 
-/// Takes a `Result` and returns the unwrapped `Ok` value, or panics if it's `Err`.
+/// Create a [`ConstChunksExact`] iterator over chunks of exactly `n` elements of a slice,
+/// like `slice::chunks_exact`. Any trailing elements that don't fit in a chunk are available
+/// from [`ConstChunksExact::remainder`]. Panics if `n` is zero.
 ///
-/// See also [`unwrap_some!`] and [`expect_ok!`].
+/// ```rust
+/// # use const_it::slice_chunks_exact;
+/// const fn sum_pairs(values: &[u32]) -> u32 {
+///     let mut chunks = slice_chunks_exact!(values, 2);
+///     let mut sum = 0;
+///     while let Some(pair) = chunks.next() {
+///         sum += pair[0] * pair[1];
+///     }
+///     sum + chunks.remainder().len() as u32
+/// }
+/// const SUM: u32 = sum_pairs(&[1, 2, 3, 4, 5]); // 15
+/// ```
 #[macro_export]
-macro_rules! unwrap_ok {
-    ($expr:expr) => {
-        $crate::expect_ok!($expr, "unwrapped Err value")
+macro_rules! slice_chunks_exact {
+    ($slice:expr, $n:expr) => {
+        $crate::ConstChunksExact::new($slice, $n)
     };
 }
 
-/// Takes an `Option` and returns the unwrapped `Some` value, or panics if it's `None`.
+/// Add a value to each element of an array of a primitive integer type, returning a new array.
+/// The addition wraps on overflow. The array can be passed by value or by reference.
 ///
-/// See also [`unwrap_ok!`] and [`expect_some!`].
+/// ```rust
+/// # use const_it::slice_map_add;
+/// const ARRAY: [u8; 3] = slice_map_add!([1u8, 2, 3], 10); // [11, 12, 13]
+/// ```
+///
+/// See also [`slice_map_mul!`].
 #[macro_export]
-macro_rules! unwrap_some {
-    ($expr:expr) => {
-        $crate::expect_some!($expr, "unwrapped None value")
+macro_rules! slice_map_add {
+    ($array:expr, $n:expr) => {
+        $crate::__internal::ArrayMap($array).add($n)
     };
 }
 
-/// Takes a `Result` and evaluates to the unwrapped `Ok` value, or if it's `Err`, returns the `Err`
-/// to the current function's caller.
+/// Multiply each element of an array of a primitive integer type by a value, returning a new
+/// array. The multiplication wraps on overflow. The array can be passed by value or by reference.
 ///
-/// See also [`unwrap_some_or_return!`].
+/// ```rust
+/// # use const_it::slice_map_mul;
+/// const ARRAY: [u8; 3] = slice_map_mul!([1u8, 2, 3], 10); // [10, 20, 30]
+/// ```
+///
+/// See also [`slice_map_add!`].
 #[macro_export]
-macro_rules! unwrap_ok_or_return {
-    ($expr:expr) => {
-        match $expr {
-            ::core::result::Result::Ok(value) => value,
-            ::core::result::Result::Err(err) => return ::core::result::Result::Err(err),
-        }
+macro_rules! slice_map_mul {
+    ($array:expr, $n:expr) => {
+        $crate::__internal::ArrayMap($array).mul($n)
     };
 }
 
-/// Takes an `Option` and evaluates to the unwrapped `Some` value, or if it's `None`, returns the `None`
-/// to the current function's caller.
+/// Sort an array in ascending order, returning a new array. This only works for arrays of
+/// primitive integer types, `char` and `bool`. The array can be passed by value or by reference.
 ///
-/// See also [`unwrap_ok_or_return!`].
+/// ```rust
+/// # use const_it::slice_sort;
+/// const SORTED: [i32; 4] = slice_sort!([3i32, -1, 2, 0]); // [-1, 0, 2, 3]
+/// ```
+///
+/// See also [`slice_sort_desc!`].
 #[macro_export]
-macro_rules! unwrap_some_or_return {
-    ($expr:expr) => {
-        match $expr {
-            ::core::option::Option::Some(value) => value,
-            ::core::option::Option::None => return ::core::option::Option::None,
-        }
+macro_rules! slice_sort {
+    ($array:expr) => {
+        $crate::__internal::ArrayMap($array).sort()
     };
 }
 
-mod slice;
-
-#[doc(hidden)]
+/// Sort an array in descending order, returning a new array. This only works for arrays of
+/// primitive integer types, `char` and `bool`. The array can be passed by value or by reference.
+///
+/// ```rust
+/// # use const_it::slice_sort_desc;
+/// const SORTED: [i32; 4] = slice_sort_desc!([3i32, -1, 2, 0]); // [3, 2, 0, -1]
+/// ```
+///
+/// See also [`slice_sort!`].
+#[macro_export]
+macro_rules! slice_sort_desc {
+    ($array:expr) => {
+        $crate::__internal::ArrayMap($array).sort_desc()
+    };
+}
+
+/// Convert the ASCII letters in a byte array to uppercase, returning a new array. Other bytes
+/// are left unchanged. The array can be passed by value or by reference.
+///
+/// ```rust
+/// # use const_it::slice_to_ascii_uppercase_bytes;
+/// const KEY: [u8; 12] = slice_to_ascii_uppercase_bytes!(b"Content-Type"); // *b"CONTENT-TYPE"
+/// ```
+///
+/// See also [`slice_to_ascii_lowercase_bytes!`].
+#[macro_export]
+macro_rules! slice_to_ascii_uppercase_bytes {
+    ($bytes:expr) => {
+        $crate::__internal::ArrayMap($bytes).to_ascii_uppercase()
+    };
+}
+
+/// Convert the ASCII letters in a byte array to lowercase, returning a new array. Other bytes
+/// are left unchanged. The array can be passed by value or by reference.
+///
+/// ```rust
+/// # use const_it::slice_to_ascii_lowercase_bytes;
+/// const KEY: [u8; 12] = slice_to_ascii_lowercase_bytes!(b"Content-Type"); // *b"content-type"
+/// ```
+///
+/// See also [`slice_to_ascii_uppercase_bytes!`].
+#[macro_export]
+macro_rules! slice_to_ascii_lowercase_bytes {
+    ($bytes:expr) => {
+        $crate::__internal::ArrayMap($bytes).to_ascii_lowercase()
+    };
+}
+
+/// Compare two slices lexicographically, returning an `Ordering`. The ordering is the same as
+/// the `Ord` implementation for slices in `core`, so elements are compared in order and if one
+/// slice is a prefix of the other, the shorter slice is less. This only works for slices of
+/// primitive integer types, `char`, `bool` and `str`, and for slices of arrays of those types,
+/// which are compared row by row.
+///
+/// Elements are ordered by value: `false` is less than `true`, and `char`s are ordered by their
+/// unicode scalar value. `str`s are compared by their UTF-8 bytes, which gives the same order as
+/// comparing their `char`s.
+///
+/// Arrays can also be passed by value, such as the result of a macro that returns an array.
+#[macro_export]
+macro_rules! slice_cmp {
+    ($a:expr, $b:expr) => {
+        $crate::__internal::SliceRef($crate::__internal::SliceArg($a).get()).cmp(
+            $crate::__internal::SliceRef($crate::__internal::SliceArg($b).get()),
+        )
+    };
+}
+
+/// Find the first position where two slices differ, returning `Option<(usize, Ordering)>` with
+/// the index and the ordering of the elements at that index, or `None` if the slices are equal.
+/// If one slice is a prefix of the other, the index is the length of the shorter slice and the
+/// shorter slice is less. The ordering is always the same as [`slice_cmp!`] would give. `str`s
+/// are compared by their UTF-8 bytes, and the index is a byte index. This only works for slices
+/// of primitive integer types, `char`, `bool` and `str`.
+///
+/// ```rust
+/// # use const_it::slice_first_diff;
+/// # use core::cmp::Ordering;
+/// const DIFF: Option<(usize, Ordering)> = slice_first_diff!(b"abc", b"abd"); // Some((2, Ordering::Less))
+/// ```
+#[macro_export]
+macro_rules! slice_first_diff {
+    ($a:expr, $b:expr) => {
+        $crate::__internal::SliceRef($a)
+            .as_slice()
+            .first_diff($crate::__internal::SliceRef($b).as_slice())
+    };
+}
+
+/// Compare two slices in shortlex order, returning an `Ordering`. Shorter slices are less than
+/// longer slices, and slices of equal length are compared lexicographically like [`slice_cmp!`].
+/// `str`s are compared by their length in bytes. This only works for slices of primitive integer
+/// types, `char`, `bool` and `str`.
+///
+/// ```rust
+/// # use const_it::{slice_cmp, slice_shortlex_cmp};
+/// # use core::cmp::Ordering;
+/// const SHORTLEX: Ordering = slice_shortlex_cmp!("b", "aa"); // Ordering::Less
+/// const LEX: Ordering = slice_cmp!("b", "aa"); // Ordering::Greater
+/// ```
+#[macro_export]
+macro_rules! slice_shortlex_cmp {
+    ($a:expr, $b:expr) => {
+        $crate::__internal::SliceRef($a)
+            .as_slice()
+            .shortlex_cmp($crate::__internal::SliceRef($b).as_slice())
+    };
+}
+
+/// Compare two pairs of slices lexicographically, returning an `Ordering`. The first components
+/// are compared first, and the second components are only compared if the first are equal,
+/// like the ordering of tuples. This only works for slices of primitive integer types and `str`.
+///
+/// ```rust
+/// # use { const_it::slice_cmp_tuple, core::cmp::Ordering };
+/// const CMP: Ordering = slice_cmp_tuple!(("key", b"\x01"), ("key", b"\x02")); // Ordering::Less
+/// ```
+#[macro_export]
+macro_rules! slice_cmp_tuple {
+    (($a0:expr, $a1:expr $(,)?), ($b0:expr, $b1:expr $(,)?) $(,)?) => {
+        match $crate::slice_cmp!($a0, $b0) {
+            ::core::cmp::Ordering::Equal => $crate::slice_cmp!($a1, $b1),
+            ordering => ordering,
+        }
+    };
+}
+
+/// Compare two slices, returning an `Option<Ordering>`. Currently all supported types always return `Some`.
+/// This only works for slices of primitive integer types, `char`, `bool` and `str`.
+/// See [`slice_cmp!`] for how elements are ordered.
+#[macro_export]
+macro_rules! slice_partial_cmp {
+    ($a:expr, $b:expr) => {
+        $crate::__internal::SliceRef($crate::__internal::SliceArg($a).get()).partial_cmp(
+            $crate::__internal::SliceRef($crate::__internal::SliceArg($b).get()),
+        )
+    };
+}
+
+/// Check if two slices are equal. This only works for slices of primitive integer types, `char`, `bool` and `str`,
+/// and for slices of arrays of those types. Arrays can also be passed by value.
+/// Slices of different lengths are never equal, and no elements are read in that case.
+///
+/// ```rust
+/// # use const_it::{slice_eq, slice_sort};
+/// const TABLE: [[u8; 2]; 2] = [[1, 2], [3, 4]];
+/// const EQ: bool = slice_eq!(&TABLE, &[[1, 2], [3, 4]]); // true
+/// const SORTED: bool = slice_eq!(slice_sort!([3u8, 1, 2]), [1u8, 2, 3]); // true
+/// ```
+#[macro_export]
+macro_rules! slice_eq {
+    ($a:expr, $b:expr) => {
+        $crate::__internal::SliceRef($crate::__internal::SliceArg($a).get()).eq(
+            $crate::__internal::SliceRef($crate::__internal::SliceArg($b).get()),
+        )
+    };
+}
+
+/// Check if two byte slices are equal, comparing eight bytes at a time. This gives the same
+/// result as [`slice_eq!`], but can be faster for long slices, since it only needs to find out
+/// whether the slices are equal and not how they're ordered.
+///
+/// ```rust
+/// # use const_it::slice_fast_eq;
+/// const A: &[u8] = b"a long const byte buffer";
+/// const EQ: bool = slice_fast_eq!(A, b"a long const byte buffer"); // true
+/// ```
+#[macro_export]
+macro_rules! slice_fast_eq {
+    ($a:expr, $b:expr) => {
+        $crate::ops::fast_eq_bytes($a, $b)
+    };
+}
+
+/// Check if a byte slice is equal to the UTF-8 bytes of a `str`.
+///
+/// ```rust
+/// # use const_it::slice_eq_bytes_str;
+/// const STATUS: &[u8] = b"OK";
+/// const EQ: bool = slice_eq_bytes_str!(STATUS, "OK"); // true
+/// ```
+#[macro_export]
+macro_rules! slice_eq_bytes_str {
+    ($bytes:expr, $s:expr) => {{
+        let (bytes, s): (&[::core::primitive::u8], &::core::primitive::str) = ($bytes, $s);
+        $crate::slice_eq!(bytes, s.as_bytes())
+    }};
+}
+
+/// Compare the UTF-8 bytes of a `str` to a byte slice lexicographically, returning an `Ordering`.
+///
+/// ```rust
+/// # use { const_it::str_bytes_cmp, core::cmp::Ordering };
+/// const CMP: Ordering = str_bytes_cmp!("abc", b"abd"); // Ordering::Less
+/// ```
+///
+/// See also [`slice_eq_bytes_str!`].
+#[macro_export]
+macro_rules! str_bytes_cmp {
+    ($s:expr, $bytes:expr) => {{
+        let s: &::core::primitive::str = $s;
+        $crate::ops::cmp_bytes(s.as_bytes(), $bytes)
+    }};
+}
+
+/// Compare two nul-terminated byte strings, like the contents of a `CStr`, returning an
+/// `Ordering`. Only the bytes before the first nul byte in each slice are compared, and a slice
+/// without a nul byte is compared in full.
+///
+/// ```rust
+/// # use { const_it::cstr_cmp, core::cmp::Ordering };
+/// const CMP: Ordering = cstr_cmp!(b"ab\0xx", b"ac\0yy"); // Ordering::Less
+/// ```
+#[macro_export]
+macro_rules! cstr_cmp {
+    ($a:expr, $b:expr) => {
+        $crate::ops::cmp_cstr($a, $b)
+    };
+}
+
+/// Check if a byte slice matches a pattern, where a wildcard byte in the pattern matches any
+/// byte. The slice and the pattern must have the same length.
+///
+/// ```rust
+/// # use const_it::slice_eq_wildcard;
+/// const MATCHES: bool = slice_eq_wildcard!(b"axc", b"a_c", b'_'); // true
+/// ```
+#[macro_export]
+macro_rules! slice_eq_wildcard {
+    ($slice:expr, $pattern:expr, $wildcard:expr) => {
+        $crate::ops::eq_wildcard_bytes($slice, $pattern, $wildcard)
+    };
+}
+
+/// Get the length of a `str` in bytes. Note that this is not the number of `char`s
+/// if the string contains non-ASCII characters.
+///
+/// ```rust
+/// # use const_it::str_byte_len;
+/// const LEN: usize = str_byte_len!("✨"); // 3
+/// ```
+#[macro_export]
+macro_rules! str_byte_len {
+    ($s:expr) => {{
+        let s: &::core::primitive::str = $s;
+        s.len()
+    }};
+}
+
+/// Get the byte at the specified `usize` index of a `str`, or `None` if the index is out
+/// of range. Note that this returns a single byte of the UTF-8 encoding, not a `char`.
+///
+/// ```rust
+/// # use const_it::str_byte;
+/// const BYTE: Option<u8> = str_byte!("abc", 1); // Some(b'b')
+/// const NONE: Option<u8> = str_byte!("abc", 3); // None
+/// ```
+#[macro_export]
+macro_rules! str_byte {
+    ($s:expr, $index:expr) => {{
+        let (s, index): (&::core::primitive::str, ::core::primitive::usize) = ($s, $index);
+        if index < s.len() {
+            ::core::option::Option::Some(s.as_bytes()[index])
+        } else {
+            ::core::option::Option::None
+        }
+    }};
+}
+
+/// Get a reference to the first `N` bytes of a `str` as a byte array, returning
+/// `Option<&[u8; N]>`, or `None` if the string is shorter than `N` bytes. The length must be
+/// a constant. These are raw bytes of the UTF-8 encoding, so the length doesn't have to be on
+/// a codepoint boundary.
+///
+/// ```rust
+/// # use const_it::str_first_bytes;
+/// const MAGIC: Option<&[u8; 3]> = str_first_bytes!("PNG image", 3); // Some(b"PNG")
+/// ```
+#[macro_export]
+macro_rules! str_first_bytes {
+    ($s:expr, $n:expr) => {{
+        let s: &::core::primitive::str = $s;
+        s.as_bytes().first_chunk::<{ $n }>()
+    }};
+}
+
+/// Truncate a `str` to at most a number of bytes, returning the longest prefix that fits and
+/// ends on a codepoint boundary. If the limit is inside a codepoint, the whole codepoint is
+/// left out. This is the same as [`slice_clamped!`] with a `..max_bytes` range.
+///
+/// ```rust
+/// # use const_it::str_truncate_to;
+/// const TRUNCATED: &str = str_truncate_to!("ab✨", 4); // "ab"
+/// ```
+#[macro_export]
+macro_rules! str_truncate_to {
+    ($s:expr, $max_bytes:expr) => {{
+        let (s, max_bytes): (&::core::primitive::str, ::core::primitive::usize) = ($s, $max_bytes);
+        $crate::slice_clamped!(s, ..max_bytes)
+    }};
+}
+
+/// Check if a `str` is empty, i.e. has a length of zero bytes.
+///
+/// ```rust
+/// # use const_it::str_is_empty;
+/// const EMPTY: bool = str_is_empty!(""); // true
+/// ```
+#[macro_export]
+macro_rules! str_is_empty {
+    ($s:expr) => {{
+        let s: &::core::primitive::str = $s;
+        s.is_empty()
+    }};
+}
+
+/// Skip the leading ASCII whitespace of a `str`, returning the rest of the string. This uses
+/// the same definition of whitespace as `u8::is_ascii_whitespace`, and is meant as the
+/// whitespace skipping step of a const lexer.
+///
+/// ```rust
+/// # use const_it::str_skip_ascii_whitespace;
+/// const TOKEN: &str = str_skip_ascii_whitespace!("  \n tok "); // "tok "
+/// ```
+///
+/// See also [`str_leading_whitespace_len!`].
+#[macro_export]
+macro_rules! str_skip_ascii_whitespace {
+    ($s:expr) => {{
+        let s: &::core::primitive::str = $s;
+        s.trim_ascii_start()
+    }};
+}
+
+/// Get the number of bytes of leading ASCII whitespace in a `str`, i.e. the number of bytes
+/// skipped by [`str_skip_ascii_whitespace!`].
+///
+/// ```rust
+/// # use const_it::str_leading_whitespace_len;
+/// const LEN: usize = str_leading_whitespace_len!("   tok"); // 3
+/// ```
+#[macro_export]
+macro_rules! str_leading_whitespace_len {
+    ($s:expr) => {{
+        let s: &::core::primitive::str = $s;
+        s.len() - s.trim_ascii_start().len()
+    }};
+}
+
+/// Check if a `str` is an ASCII identifier: it must be non-empty, start with an ASCII letter
+/// or `_`, and contain only ASCII letters, digits and `_`. Note that this accepts `_` and
+/// keywords, which aren't valid Rust identifiers.
+///
+/// ```rust
+/// # use const_it::str_is_ascii_identifier;
+/// const VALID: bool = str_is_ascii_identifier!("_field1"); // true
+/// const INVALID: bool = str_is_ascii_identifier!("1field"); // false
+/// ```
+#[macro_export]
+macro_rules! str_is_ascii_identifier {
+    ($s:expr) => {
+        $crate::__internal::is_ascii_identifier($s)
+    };
+}
+
+/// Create a [`ConstSplitWhitespace`] iterator over the non-empty parts of a `str` separated
+/// by runs of ASCII whitespace, like `str::split_ascii_whitespace`.
+///
+/// ```rust
+/// # use const_it::str_split_whitespace;
+/// const fn count_words(s: &str) -> usize {
+///     let mut words = str_split_whitespace!(s);
+///     let mut count = 0;
+///     while words.next().is_some() {
+///         count += 1;
+///     }
+///     count
+/// }
+/// const COUNT: usize = count_words("  const  it\n"); // 2
+/// ```
+#[macro_export]
+macro_rules! str_split_whitespace {
+    ($s:expr) => {
+        $crate::ConstSplitWhitespace::new($s)
+    };
+}
+
+/// Find the first occurrence of a `char` in a `str`, returning `Option<usize>` with its byte
+/// index, or `None` if it wasn't found.
+///
+/// ```rust
+/// # use const_it::str_find_char;
+/// const INDEX: Option<usize> = str_find_char!("a✨b✨", '✨'); // Some(1)
+/// ```
+///
+/// See also [`str_rfind_char!`].
+#[macro_export]
+macro_rules! str_find_char {
+    ($s:expr, $ch:expr) => {
+        $crate::__internal::find_char($s, $ch)
+    };
+}
+
+/// Find the last occurrence of a `char` in a `str`, returning `Option<usize>` with its byte
+/// index, or `None` if it wasn't found.
+///
+/// ```rust
+/// # use const_it::str_rfind_char;
+/// const INDEX: Option<usize> = str_rfind_char!("a✨b✨", '✨'); // Some(5)
+/// ```
+///
+/// See also [`str_find_char!`].
+#[macro_export]
+macro_rules! str_rfind_char {
+    ($s:expr, $ch:expr) => {
+        $crate::__internal::rfind_char($s, $ch)
+    };
+}
+
+/// Count the number of occurrences of a `char` in a `str`.
+///
+/// ```rust
+/// # use const_it::str_count_char;
+/// const FIELDS: usize = str_count_char!("a,b,c", ',') + 1; // 3
+/// ```
+#[macro_export]
+macro_rules! str_count_char {
+    ($s:expr, $ch:expr) => {
+        $crate::__internal::count_char($s, $ch)
+    };
+}
+
+/// Count the number of lines in a `str`, like `str::lines().count()`. Lines end with `\n` or
+/// `\r\n`, and a line ending at the end of the string doesn't start a new empty line.
+///
+/// ```rust
+/// # use const_it::str_lines_count;
+/// const TEXT: &str = "one\ntwo\r\nthree\n";
+/// const LINES: [&str; str_lines_count!(TEXT)] = ["one", "two", "three"];
+/// ```
+#[macro_export]
+macro_rules! str_lines_count {
+    ($s:expr) => {
+        $crate::__internal::lines_count($s)
+    };
+}
+
+/// Get a line of a `str` by its zero-based index, returning `Option<&str>` with the line
+/// without its line ending, or `None` if there aren't enough lines. Lines are the same as
+/// for `str::lines`.
+///
+/// ```rust
+/// # use const_it::str_line_at;
+/// const LINE: Option<&str> = str_line_at!("one\ntwo\r\nthree", 1); // Some("two")
+/// ```
+///
+/// See also [`str_lines_count!`].
+#[macro_export]
+macro_rules! str_line_at {
+    ($s:expr, $n:expr) => {
+        $crate::__internal::line_at($s, $n)
+    };
+}
+
+/// Create a [`ConstLinesInclusive`] iterator over the lines of a `str` including their line
+/// endings, like `str::split_inclusive('\n')`. Both `\n` and `\r\n` are kept at the end of
+/// each line, and a final line without a line ending is also included.
+///
+/// ```rust
+/// # use const_it::str_lines_inclusive;
+/// const fn last_line(s: &str) -> &str {
+///     let mut lines = str_lines_inclusive!(s);
+///     let mut last = "";
+///     while let Some(line) = lines.next() {
+///         last = line;
+///     }
+///     last
+/// }
+/// const LAST: &str = last_line("one\ntwo\r\n"); // "two\r\n"
+/// ```
+///
+/// See also [`str_line_at!`].
+#[macro_export]
+macro_rules! str_lines_inclusive {
+    ($s:expr) => {
+        $crate::ConstLinesInclusive::new($s)
+    };
+}
+
+/// Pad the bytes of a `str` to a fixed length with an ASCII fill character, returning a byte
+/// array. The length must be a constant. Panics (or fails to compile, in a const context) if the
+/// fill character isn't ASCII or the string is longer than the length.
+///
+/// ```rust
+/// # use const_it::str_pad_end_ascii;
+/// const PADDED: [u8; 5] = str_pad_end_ascii!("hi", 5, '.'); // *b"hi..."
+/// ```
+///
+/// See also [`str_pad_end!`], which returns a [`StrBuf`].
+#[macro_export]
+macro_rules! str_pad_end_ascii {
+    ($s:expr, $len:expr, $fill:expr) => {
+        $crate::__internal::pad_end_ascii::<{ $len }>($s, $fill)
+    };
+}
+
+/// Pad a `str` to a fixed length in bytes with an ASCII fill character, returning a [`StrBuf`].
+/// The length must be a constant. Panics (or fails to compile, in a const context) if the
+/// fill character isn't ASCII or the string is longer than the length.
+///
+/// ```rust
+/// # use const_it::{str_pad_end, StrBuf};
+/// const PADDED: StrBuf<5> = str_pad_end!("hi", 5, '.');
+/// const STR: &str = PADDED.as_str(); // "hi..."
+/// ```
+///
+/// See also [`str_pad_end_ascii!`].
+#[macro_export]
+macro_rules! str_pad_end {
+    ($s:expr, $len:expr, $fill:expr) => {
+        $crate::__internal::pad_end_str::<{ $len }>($s, $fill)
+    };
+}
+
+/// Concatenate a list of `str`s into a [`StrBuf`]. The strings must be constants, as the
+/// length of the output is computed at compile time.
+///
+/// ```rust
+/// # use const_it::{str_concat, StrBuf};
+/// const DIR: &str = "foo";
+/// const PATH: StrBuf<7> = str_concat!(DIR, "/", "bar");
+/// const STR: &str = PATH.as_str(); // "foo/bar"
+/// ```
+///
+/// See also [`str_join!`] and [`slice_concat_many!`].
+#[macro_export]
+macro_rules! str_concat {
+    ($($s:expr),+ $(,)?) => {{
+        const LEN: ::core::primitive::usize = 0 $(+ $s.len())+;
+        $crate::__internal::concat_str::<LEN>(&[$($s),+])
+    }};
+}
+
+/// Join a list of `str`s with a separator between each of them into a [`StrBuf`]. The strings
+/// and the separator must be constants, as the length of the output is computed at compile time.
+///
+/// ```rust
+/// # use const_it::{str_join, StrBuf};
+/// const LIST: StrBuf<7> = str_join!(["a", "b", "c"], ", ");
+/// const STR: &str = LIST.as_str(); // "a, b, c"
+/// ```
+///
+/// See also [`str_concat!`].
+#[macro_export]
+macro_rules! str_join {
+    ([$($s:expr),+ $(,)?], $sep:expr) => {{
+        const LEN: ::core::primitive::usize =
+            0 $(+ $s.len())+ + $sep.len() * ([$($s),+].len() - 1);
+        $crate::__internal::join_str::<LEN>(&[$($s),+], $sep)
+    }};
+}
+
+/// Reverse an ASCII `str`, returning a byte array. The string must be a constant, as the length
+/// of the output array is computed at compile time. Panics (or fails to compile, in a const
+/// context) if the string isn't ASCII, since reversing the bytes of a multibyte codepoint would
+/// produce invalid UTF-8.
+///
+/// ```rust
+/// # use const_it::str_reverse_ascii;
+/// const REVERSED: [u8; 3] = str_reverse_ascii!("abc"); // *b"cba"
+/// ```
+///
+/// See also [`str_try_reverse_ascii!`].
+#[macro_export]
+macro_rules! str_reverse_ascii {
+    ($s:expr) => {{
+        const LEN: ::core::primitive::usize = $s.len();
+        $crate::__internal::reverse_ascii::<LEN>($s)
+    }};
+}
+
+/// Reverse an ASCII `str`, returning `Result<[u8; N], &str>` with the reversed bytes, or an
+/// error message if the string isn't ASCII. The string must be a constant, as the length of
+/// the output array is computed at compile time.
+///
+/// ```rust
+/// # use const_it::str_try_reverse_ascii;
+/// const REVERSED: Result<[u8; 3], &str> = str_try_reverse_ascii!("abc"); // Ok(*b"cba")
+/// const ERR: Result<[u8; 3], &str> = str_try_reverse_ascii!("✨"); // Err(_)
+/// ```
+///
+/// See also [`str_reverse_ascii!`].
+#[macro_export]
+macro_rules! str_try_reverse_ascii {
+    ($s:expr) => {{
+        const LEN: ::core::primitive::usize = $s.len();
+        $crate::__internal::try_reverse_ascii::<LEN>($s)
+    }};
+}
+
+/// Capitalize a `str` by converting its first character to ASCII uppercase, returning a byte
+/// array. Only an ASCII letter at the start is changed, so the result is valid UTF-8 if the
+/// input was. The string must be a constant, as the length of the output array is computed at
+/// compile time.
+///
+/// ```rust
+/// # use const_it::str_capitalize_ascii;
+/// const NAME: [u8; 5] = str_capitalize_ascii!("hello"); // *b"Hello"
+/// ```
+#[macro_export]
+macro_rules! str_capitalize_ascii {
+    ($s:expr) => {{
+        const LEN: ::core::primitive::usize = $s.len();
+        $crate::__internal::capitalize_ascii::<LEN>($s)
+    }};
+}
+
+/// Check if a `str` is equal to any of the strings in a list. Stops comparing at the first match.
+///
+/// ```rust
+/// # use const_it::str_eq_any;
+/// const IS_METHOD: bool = str_eq_any!("GET", ["GET", "POST", "PUT"]); // true
+/// ```
+#[macro_export]
+macro_rules! str_eq_any {
+    ($s:expr, [$($candidate:expr),+ $(,)?]) => {{
+        let s: &::core::primitive::str = $s;
+        false $(|| $crate::slice_eq!(s, $candidate))+
+    }};
+}
+
+/// Check if the `char`s of a `str` are equal to a list of `char`s, in order.
+///
+/// ```rust
+/// # use const_it::str_chars_eq;
+/// const EQ: bool = str_chars_eq!("a✨", ['a', '✨']); // true
+/// ```
+#[macro_export]
+macro_rules! str_chars_eq {
+    ($s:expr, [$($ch:expr),* $(,)?]) => {
+        $crate::__internal::chars_eq($s, &[$($ch),*])
+    };
+}
+
+/// Check if two slices are equal, ignoring ASCII case. ASCII letters are folded and all other
+/// elements must match exactly. This only works for slices of `u8` and `char`, and `str`.
+///
+/// ```rust
+/// # use const_it::slice_eq_ignore_ascii_case;
+/// const BYTES: bool = slice_eq_ignore_ascii_case!(b"GET", b"get"); // true
+/// const CHARS: bool = slice_eq_ignore_ascii_case!(&['H', 'I'], &['h', 'i']); // true
+/// ```
+///
+/// See also [`str_eq_ignore_ascii_case!`].
+#[macro_export]
+macro_rules! slice_eq_ignore_ascii_case {
+    ($a:expr, $b:expr) => {
+        $crate::__internal::SliceRef($a)
+            .as_slice()
+            .eq_ignore_ascii_case($crate::__internal::SliceRef($b).as_slice())
+    };
+}
+
+/// Check if two `str`s are equal, ignoring ASCII case. Only the ASCII letters `a-z` and `A-Z`
+/// are folded; all other characters, including non-ASCII letters, must match exactly.
+///
+/// ```rust
+/// # use const_it::str_eq_ignore_ascii_case;
+/// const EQ: bool = str_eq_ignore_ascii_case!("Content-Type", "content-type"); // true
+/// const NE: bool = str_eq_ignore_ascii_case!("Ä", "ä"); // false
+/// ```
+///
+/// Both arguments must be `str`s; byte slices are rejected. Use [`slice_eq_ignore_ascii_case!`]
+/// to compare byte or `char` slices.
+///
+/// ```compile_fail
+/// # use const_it::str_eq_ignore_ascii_case;
+/// const EQ: bool = str_eq_ignore_ascii_case!("Content-Type", b"content-type");
+/// ```
+#[macro_export]
+macro_rules! str_eq_ignore_ascii_case {
+    ($a:expr, $b:expr) => {{
+        let (a, b): (&::core::primitive::str, &::core::primitive::str) = ($a, $b);
+        $crate::__internal::SliceRef(a).eq_ignore_ascii_case($crate::__internal::SliceRef(b))
+    }};
+}
+
+/// Check if the first `n` elements of two slices are equal. Returns `false` if either slice is
+/// shorter than `n` or, for `str`, if `n` isn't on a codepoint boundary. This only works for
+/// slices of primitive integer types and `str`.
+///
+/// ```rust
+/// # use const_it::slice_eq_prefix;
+/// const EQ: bool = slice_eq_prefix!(b"\x89PNG\r\n", b"\x89PNG\n", 4); // true
+/// ```
+#[macro_export]
+macro_rules! slice_eq_prefix {
+    ($a:expr, $b:expr, $n:expr) => {{
+        let n: ::core::primitive::usize = $n;
+        match (
+            $crate::slice_try_split_at!($a, n),
+            $crate::slice_try_split_at!($b, n),
+        ) {
+            (::core::option::Option::Some((a, _)), ::core::option::Option::Some((b, _))) => {
+                $crate::slice_eq!(a, b)
+            }
+            _ => false,
+        }
+    }};
+}
+
+/// Check if a slice contains any of the values in a list. This only works for slices of
+/// primitive integer types, `char` and `bool`.
+///
+/// ```rust
+/// # use const_it::slice_contains_any;
+/// const HAS_SEPARATOR: bool = slice_contains_any!(b"a,b;c", [b',', b';']); // true
+/// ```
+#[macro_export]
+macro_rules! slice_contains_any {
+    ($slice:expr, [$($value:expr),+ $(,)?]) => {{
+        let slice = $crate::__internal::SliceRef($slice).as_slice();
+        false $(|| slice.position($value).is_some())+
+    }};
+}
+
+/// Split a slice around the first occurrence of a separator element, returning
+/// `Option<(&[T], &[T])>` with the parts before and after the separator, or `None` if the
+/// separator wasn't found. This only works for slices of primitive integer types, `char` and `bool`.
+///
+/// ```rust
+/// # use const_it::slice_split_once;
+/// const SPLIT: Option<(&[u8], &[u8])> = slice_split_once!(b"a.b.c", b'.'); // Some((b"a", b"b.c"))
+/// ```
+///
+/// See also [`slice_rsplit_once!`].
+#[macro_export]
+macro_rules! slice_split_once {
+    ($slice:expr, $separator:expr) => {
+        $crate::__internal::SliceRef($slice)
+            .as_slice()
+            .split_once($separator)
+    };
+}
+
+/// Split a slice after the first occurrence of a separator element, returning
+/// `Option<(&[T], &[T])>` where the separator is kept at the end of the first part, like the
+/// first item of `slice::split_inclusive`, or `None` if the separator wasn't found.
+/// This only works for slices of primitive integer types, `char` and `bool`.
+///
+/// ```rust
+/// # use const_it::slice_split_inclusive_once;
+/// const SPLIT: Option<(&[u8], &[u8])> = slice_split_inclusive_once!(b"ab\ncd", b'\n'); // Some((b"ab\n", b"cd"))
+/// ```
+///
+/// See also [`slice_split_once!`].
+#[macro_export]
+macro_rules! slice_split_inclusive_once {
+    ($slice:expr, $separator:expr) => {
+        $crate::__internal::SliceRef($slice)
+            .as_slice()
+            .split_inclusive_once($separator)
+    };
+}
+
+/// Split a slice around the last occurrence of a separator element, returning
+/// `Option<(&[T], &[T])>` with the parts before and after the separator, or `None` if the
+/// separator wasn't found. This only works for slices of primitive integer types, `char` and `bool`.
+///
+/// ```rust
+/// # use const_it::slice_rsplit_once;
+/// const SPLIT: Option<(&[u8], &[u8])> = slice_rsplit_once!(b"a.b.c", b'.'); // Some((b"a.b", b"c"))
+/// ```
+///
+/// See also [`slice_split_once!`].
+#[macro_export]
+macro_rules! slice_rsplit_once {
+    ($slice:expr, $separator:expr) => {
+        $crate::__internal::SliceRef($slice)
+            .as_slice()
+            .rsplit_once($separator)
+    };
+}
+
+/// Assert that two slices are equal, panicking with the message given as the third argument if
+/// they aren't. Evaluates to `()`. When used in a const context, a failed assertion is a
+/// compile time error. This only works for slices of primitive integer types, `char`, `bool` and `str`.
+///
+/// ```rust
+/// # use const_it::const_assert_slice_eq;
+/// const TABLE: [u8; 3] = [1, 2, 3];
+/// const _: () = const_assert_slice_eq!(&TABLE, &[1, 2, 3], "unexpected table");
+/// ```
+///
+/// ```compile_fail
+/// # use const_it::const_assert_slice_eq;
+/// const _: () = const_assert_slice_eq!("abc", "abd", "strings differ");
+/// ```
+#[macro_export]
+macro_rules! const_assert_slice_eq {
+    ($a:expr, $b:expr, $message:expr $(,)?) => {
+        if !$crate::slice_eq!($a, $b) {
+            ::core::panic!("{}", $message);
+        }
+    };
+}
+
+/// Check if a slice starts with another slice. This only works for slices of primitive integer types and `str`.
+#[macro_export]
+macro_rules! slice_starts_with {
+    ($s:expr, $prefix:expr) => {
+        $crate::slice_strip_prefix!($s, $prefix).is_some()
+    };
+}
+
+/// Check if a slice ends with another slice. This only works for slices of primitive integer types and `str`.
+#[macro_export]
+macro_rules! slice_ends_with {
+    ($s:expr, $prefix:expr) => {
+        $crate::slice_strip_suffix!($s, $prefix).is_some()
+    };
+}
+
+/// Check if a slice starts with any of the prefixes in a list, returning `Option<usize>` with
+/// the index in the list of the first matching prefix, or `None` if none of them match.
+/// This only works for slices of primitive integer types and `str`.
+///
+/// ```rust
+/// # use const_it::slice_starts_with_any;
+/// const SCHEME: Option<usize> = slice_starts_with_any!("https://", ["http://", "https://"]); // Some(1)
+/// ```
+#[macro_export]
+macro_rules! slice_starts_with_any {
+    ($s:expr, [$($prefix:expr),+ $(,)?]) => {{
+        let s = $s;
+        let matches = [$($crate::slice_starts_with!(s, $prefix)),+];
+        let mut index: ::core::primitive::usize = 0;
+        while index < matches.len() && !matches[index] {
+            index += 1;
+        }
+        if index < matches.len() {
+            ::core::option::Option::Some(index)
+        } else {
+            ::core::option::Option::None
+        }
+    }};
+}
+
+/// Strip a prefix from a slice, returning an Option with the stripped slice on success. This only works for slices of primitive integer types and `str`.
+#[macro_export]
+macro_rules! slice_strip_prefix {
+    ($s:expr, $prefix:expr) => {
+        $crate::__internal::SliceRef($s)
+            .as_slice()
+            .strip_prefix($crate::__internal::SliceRef($prefix).as_slice())
+    };
+}
+
+/// Strip a suffix from a slice, returning an Option with the stripped slice on success. This only works for slices of primitive integer types and `str`.
+#[macro_export]
+macro_rules! slice_strip_suffix {
+    ($s:expr, $suffix:expr) => {
+        $crate::__internal::SliceRef($s)
+            .as_slice()
+            .strip_suffix($crate::__internal::SliceRef($suffix).as_slice())
+    };
+}
+
+/// Convert a slice of a primitive integer type to an array of bytes in little-endian order.
+/// The slice must be a constant, as the length of the output array is computed at compile time.
+///
+/// ```rust
+/// # use const_it::slice_to_le_bytes;
+/// const BYTES: [u8; 6] = slice_to_le_bytes!(&[0x0102u16, 0x0304, 0x0506]); // [2, 1, 4, 3, 6, 5]
+/// ```
+///
+/// See also [`slice_to_be_bytes!`].
+#[macro_export]
+macro_rules! slice_to_le_bytes {
+    ($slice:expr) => {{
+        const LEN: ::core::primitive::usize =
+            $crate::__internal::SliceRef($slice).as_slice().byte_len();
+        $crate::__internal::SliceRef($slice)
+            .as_slice()
+            .to_le_bytes::<LEN>()
+    }};
+}
+
+/// Convert a slice of a primitive integer type to an array of bytes in big-endian order.
+/// The slice must be a constant, as the length of the output array is computed at compile time.
+///
+/// ```rust
+/// # use const_it::slice_to_be_bytes;
+/// const BYTES: [u8; 6] = slice_to_be_bytes!(&[0x0102u16, 0x0304, 0x0506]); // [1, 2, 3, 4, 5, 6]
+/// ```
+///
+/// See also [`slice_to_le_bytes!`].
+#[macro_export]
+macro_rules! slice_to_be_bytes {
+    ($slice:expr) => {{
+        const LEN: ::core::primitive::usize =
+            $crate::__internal::SliceRef($slice).as_slice().byte_len();
+        $crate::__internal::SliceRef($slice)
+            .as_slice()
+            .to_be_bytes::<LEN>()
+    }};
+}
+
+/// Parse a byte slice as an array of values of a primitive integer type in little-endian order.
+/// The first argument is the integer type. Returns `None` if the length of the slice isn't a
+/// multiple of the size of the type. The slice must be a constant, as the length of the output
+/// array is computed at compile time.
+///
+/// ```rust
+/// # use const_it::bytes_from_le;
+/// const VALUES: Option<[u16; 2]> = bytes_from_le!(u16, &[2, 1, 4, 3]); // Some([0x0102, 0x0304])
+/// ```
+///
+/// See also [`bytes_from_be!`] and [`slice_to_le_bytes!`].
+#[macro_export]
+macro_rules! bytes_from_le {
+    ($ty:ty, $bytes:expr) => {{
+        const LEN: ::core::primitive::usize = $bytes.len() / ::core::mem::size_of::<$ty>();
+        $crate::__internal::FromBytes::<$ty>::from_le_bytes::<LEN>($bytes)
+    }};
+}
+
+/// Parse a byte slice as an array of values of a primitive integer type in big-endian order.
+/// The first argument is the integer type. Returns `None` if the length of the slice isn't a
+/// multiple of the size of the type. The slice must be a constant, as the length of the output
+/// array is computed at compile time.
+///
+/// ```rust
+/// # use const_it::bytes_from_be;
+/// const VALUES: Option<[u16; 2]> = bytes_from_be!(u16, &[1, 2, 3, 4]); // Some([0x0102, 0x0304])
+/// ```
+///
+/// See also [`bytes_from_le!`] and [`slice_to_be_bytes!`].
+#[macro_export]
+macro_rules! bytes_from_be {
+    ($ty:ty, $bytes:expr) => {{
+        const LEN: ::core::primitive::usize = $bytes.len() / ::core::mem::size_of::<$ty>();
+        $crate::__internal::FromBytes::<$ty>::from_be_bytes::<LEN>($bytes)
+    }};
+}
+
+/// Sum the elements of a slice of `f32` or `f64`. The elements are added in index order,
+/// starting from `-0.0` like `Iterator::sum`, with no compensation for rounding errors. The sum
+/// of an empty slice is `-0.0`.
+///
+/// ```rust
+/// # use const_it::slice_sum_float;
+/// const SUM: f32 = slice_sum_float!(&[1.0f32, 2.0, 3.0]); // 6.0
+/// ```
+#[macro_export]
+macro_rules! slice_sum_float {
+    ($slice:expr) => {
+        $crate::__internal::SliceRef($slice).as_slice().sum_float()
+    };
+}
+
+/// Check if two slices of `f32` or `f64` are equal bit for bit, comparing the elements with
+/// `to_bits`. Unlike `==`, a `NaN` is equal to a `NaN` with the same bit pattern, and `0.0` isn't
+/// equal to `-0.0`.
+///
+/// ```rust
+/// # use const_it::slice_bits_eq;
+/// const NAN: bool = slice_bits_eq!(&[1.0f64, f64::NAN], &[1.0f64, f64::NAN]); // true
+/// const ZERO: bool = slice_bits_eq!(&[0.0f64], &[-0.0f64]); // false
+/// ```
+#[macro_export]
+macro_rules! slice_bits_eq {
+    ($a:expr, $b:expr) => {
+        $crate::__internal::SliceRef($a)
+            .as_slice()
+            .bits_eq($crate::__internal::SliceRef($b).as_slice())
+    };
+}
+
+/// Count the set bits in all the elements of a slice, returning a `usize`. This only works for
+/// slices of primitive integer types.
+///
+/// ```rust
+/// # use const_it::slice_count_ones;
+/// const ONES: usize = slice_count_ones!(b"\xff\x00\x0f"); // 12
+/// ```
+///
+/// See also [`slice_count_zeros!`].
+#[macro_export]
+macro_rules! slice_count_ones {
+    ($slice:expr) => {
+        $crate::__internal::SliceRef($slice).as_slice().count_ones()
+    };
+}
+
+/// Count the unset bits in all the elements of a slice, returning a `usize`. This only works
+/// for slices of primitive integer types.
+///
+/// ```rust
+/// # use const_it::slice_count_zeros;
+/// const ZEROS: usize = slice_count_zeros!(b"\xff\x00\x0f"); // 12
+/// ```
+///
+/// See also [`slice_count_ones!`].
+#[macro_export]
+macro_rules! slice_count_zeros {
+    ($slice:expr) => {
+        $crate::__internal::SliceRef($slice)
+            .as_slice()
+            .count_zeros()
+    };
+}
+
+/// Get the numeric value of an ASCII digit byte in the given radix as `Option<u8>`, or `None`
+/// if the byte isn't a digit in that radix. Letters are accepted in both cases.
+/// Panics if the radix isn't in the range `2..=36`.
+///
+/// ```rust
+/// # use const_it::ascii_to_digit;
+/// const DIGIT: Option<u8> = ascii_to_digit!(b'f', 16); // Some(15)
+/// ```
+///
+/// See also [`digit_to_ascii!`].
+#[macro_export]
+macro_rules! ascii_to_digit {
+    ($byte:expr, $radix:expr) => {
+        $crate::__internal::ascii_to_digit($byte, $radix)
+    };
+}
+
+/// Get the lowercase ASCII digit byte for a numeric value in the given radix as `Option<u8>`,
+/// or `None` if the value is out of range for the radix.
+/// Panics if the radix isn't in the range `2..=36`.
+///
+/// ```rust
+/// # use const_it::digit_to_ascii;
+/// const ASCII: Option<u8> = digit_to_ascii!(15, 16); // Some(b'f')
+/// ```
+///
+/// See also [`ascii_to_digit!`].
+#[macro_export]
+macro_rules! digit_to_ascii {
+    ($value:expr, $radix:expr) => {
+        $crate::__internal::digit_to_ascii($value, $radix)
+    };
+}
+
+/// Format a `u64` as decimal digits, returning a `([u8; 20], usize)` tuple. The digits are
+/// written to the end of the array, and the `usize` is the index of the first digit. Use
+/// [`decimal_as_str!`] to get the digits as a `str`.
+///
+/// ```rust
+/// # use const_it::{decimal_as_str, u64_to_str};
+/// const DIGITS: ([u8; 20], usize) = u64_to_str!(12345);
+/// const STR: &str = decimal_as_str!(&DIGITS); // "12345"
+/// ```
+#[macro_export]
+macro_rules! u64_to_str {
+    ($value:expr) => {
+        $crate::__internal::u64_to_decimal($value)
+    };
+}
+
+/// Format an `i64` as decimal digits with a leading `-` if it's negative, returning a
+/// `([u8; 21], usize)` tuple. The digits are written to the end of the array, and the `usize`
+/// is the index of the first byte. Use [`decimal_as_str!`] to get the result as a `str`.
+///
+/// ```rust
+/// # use const_it::{decimal_as_str, i64_to_str};
+/// const DIGITS: ([u8; 21], usize) = i64_to_str!(-12345);
+/// const STR: &str = decimal_as_str!(&DIGITS); // "-12345"
+/// ```
+#[macro_export]
+macro_rules! i64_to_str {
+    ($value:expr) => {
+        $crate::__internal::i64_to_decimal($value)
+    };
+}
+
+/// Get the digits of a formatted integer from [`u64_to_str!`] or [`i64_to_str!`] as a `str`. The argument is a
+/// reference to the returned tuple.
+///
+/// ```rust
+/// # use const_it::{decimal_as_str, u64_to_str};
+/// const DIGITS: ([u8; 20], usize) = u64_to_str!(u64::MAX);
+/// const STR: &str = decimal_as_str!(&DIGITS); // "18446744073709551615"
+/// ```
+#[macro_export]
+macro_rules! decimal_as_str {
+    ($digits:expr) => {
+        $crate::__internal::decimal_as_str($digits)
+    };
+}
+
+/// Remove all elements equal to a value from the start of a slice.
+/// This only works for slices of primitive integer types, `char` and `bool`.
+///
+/// ```rust
+/// # use const_it::slice_trim_start_matches;
+/// const TRIMMED: &[u8] = slice_trim_start_matches!(b"\0\0abc", 0); // b"abc"
+/// ```
+///
+/// See also [`slice_trim_end_matches!`].
+#[macro_export]
+macro_rules! slice_trim_start_matches {
+    ($slice:expr, $value:expr) => {
+        $crate::__internal::SliceRef($slice)
+            .as_slice()
+            .trim_start_matches($value)
+    };
+}
+
+/// Remove all elements equal to a value from the end of a slice.
+/// This only works for slices of primitive integer types, `char` and `bool`.
+///
+/// ```rust
+/// # use const_it::slice_trim_end_matches;
+/// const TRIMMED: &[u8] = slice_trim_end_matches!(b"abc\0\0", 0); // b"abc"
+/// ```
+///
+/// See also [`slice_trim_start_matches!`].
+#[macro_export]
+macro_rules! slice_trim_end_matches {
+    ($slice:expr, $value:expr) => {
+        $crate::__internal::SliceRef($slice)
+            .as_slice()
+            .trim_end_matches($value)
+    };
+}
+
+/// Check if a slice reads the same forwards and backwards. This only works for slices of
+/// primitive integer types, `char`, `bool` and `str`. Strings are compared byte by byte, so a
+/// string containing multibyte characters is generally not a palindrome even if its `char`s are.
+///
+/// ```rust
+/// # use const_it::slice_is_palindrome;
+/// const PALINDROME: bool = slice_is_palindrome!(b"abba"); // true
+/// ```
+#[macro_export]
+macro_rules! slice_is_palindrome {
+    ($slice:expr) => {
+        $crate::__internal::SliceRef($slice)
+            .as_slice()
+            .is_palindrome()
+    };
+}
+
+/// Get a reference to the smallest element of a slice, or `None` if the slice is empty.
+/// If there are several smallest elements, the first one is returned.
+/// This only works for slices of primitive integer types, `char` and `bool`.
+///
+/// ```rust
+/// # use const_it::slice_min;
+/// const MIN: Option<&i32> = slice_min!(&[3i32, -1, 2]); // Some(&-1)
+/// ```
+///
+/// See also [`slice_max!`] and [`slice_min_index!`].
+#[macro_export]
+macro_rules! slice_min {
+    ($slice:expr) => {
+        $crate::__internal::SliceRef($slice).as_slice().min()
+    };
+}
+
+/// Get a reference to the largest element of a slice, or `None` if the slice is empty.
+/// If there are several largest elements, the first one is returned.
+/// This only works for slices of primitive integer types, `char` and `bool`.
+///
+/// ```rust
+/// # use const_it::slice_max;
+/// const MAX: Option<&i32> = slice_max!(&[3i32, -1, 2]); // Some(&3)
+/// ```
+///
+/// See also [`slice_min!`] and [`slice_max_index!`].
+#[macro_export]
+macro_rules! slice_max {
+    ($slice:expr) => {
+        $crate::__internal::SliceRef($slice).as_slice().max()
+    };
+}
+
+/// Get references to the smallest and the largest element of a slice in one pass, returning
+/// `Option<(&T, &T)>` with `(min, max)`, or `None` if the slice is empty. If there are several
+/// smallest or largest elements, the first ones are returned, like [`slice_min!`] and
+/// [`slice_max!`]. This only works for slices of primitive integer types, `char` and `bool`.
+///
+/// ```rust
+/// # use const_it::slice_min_max;
+/// const MIN_MAX: Option<(&i32, &i32)> = slice_min_max!(&[3i32, -1, 2]); // Some((&-1, &3))
+/// ```
+#[macro_export]
+macro_rules! slice_min_max {
+    ($slice:expr) => {
+        $crate::__internal::SliceRef($slice).as_slice().min_max()
+    };
+}
+
+/// Get the index of the smallest element of a slice, or `None` if the slice is empty.
+/// If there are several smallest elements, the index of the first one is returned.
+/// This only works for slices of primitive integer types, `char` and `bool`.
+///
+/// ```rust
+/// # use const_it::slice_min_index;
+/// const INDEX: Option<usize> = slice_min_index!(&[3i32, -1, 2, -1]); // Some(1)
+/// ```
+///
+/// See also [`slice_max_index!`] and [`slice_min!`].
+#[macro_export]
+macro_rules! slice_min_index {
+    ($slice:expr) => {
+        $crate::__internal::SliceRef($slice).as_slice().min_index()
+    };
+}
+
+/// Get the index of the largest element of a slice, or `None` if the slice is empty.
+/// If there are several largest elements, the index of the first one is returned.
+/// This only works for slices of primitive integer types, `char` and `bool`.
+///
+/// ```rust
+/// # use const_it::slice_max_index;
+/// const INDEX: Option<usize> = slice_max_index!(&[3i32, -1, 3]); // Some(0)
+/// ```
+///
+/// See also [`slice_min_index!`] and [`slice_max!`].
+#[macro_export]
+macro_rules! slice_max_index {
+    ($slice:expr) => {
+        $crate::__internal::SliceRef($slice).as_slice().max_index()
+    };
+}
+
+/// Count the elements of a slice that are less than, equal to and greater than a pivot value,
+/// returning the three counts as a tuple `(less, equal, greater)`.
+/// This only works for slices of primitive integer types, `char` and `bool`.
+///
+/// ```rust
+/// # use const_it::slice_partition_counts;
+/// const COUNTS: (usize, usize, usize) = slice_partition_counts!(&[1i32, 2, 2, 3], 2); // (1, 2, 1)
+/// ```
+#[macro_export]
+macro_rules! slice_partition_counts {
+    ($slice:expr, $pivot:expr) => {
+        $crate::__internal::SliceRef($slice)
+            .as_slice()
+            .partition_counts($pivot)
+    };
+}
+
+/// Count the number of elements at the start of a slice that are equal to a value.
+/// This only works for slices of primitive integer types, `char` and `bool`.
+///
+/// ```rust
+/// # use const_it::slice_count_leading;
+/// const COUNT: usize = slice_count_leading!(b"\0\0abc", 0); // 2
+/// ```
+///
+/// See also [`slice_count_trailing!`].
+#[macro_export]
+macro_rules! slice_count_leading {
+    ($slice:expr, $value:expr) => {
+        $crate::__internal::SliceRef($slice)
+            .as_slice()
+            .count_leading($value)
+    };
+}
+
+/// Count the number of elements at the end of a slice that are equal to a value.
+/// This only works for slices of primitive integer types, `char` and `bool`.
+///
+/// ```rust
+/// # use const_it::slice_count_trailing;
+/// const COUNT: usize = slice_count_trailing!(b"abc\0\0\0", 0); // 3
+/// ```
+///
+/// See also [`slice_count_leading!`].
+#[macro_export]
+macro_rules! slice_count_trailing {
+    ($slice:expr, $value:expr) => {
+        $crate::__internal::SliceRef($slice)
+            .as_slice()
+            .count_trailing($value)
+    };
+}
+
+/// Find the longest run of consecutive elements in a slice that are equal to a value, returning
+/// a `(usize, usize)` tuple with the start index and the length of the run. If there are several
+/// runs of the same length, the first one is returned. If the value doesn't occur in the slice,
+/// the result is `(0, 0)`. This only works for slices of primitive integer types, `char` and `bool`.
+///
+/// ```rust
+/// # use const_it::slice_longest_run;
+/// const RUN: (usize, usize) = slice_longest_run!(b"aabaaab", b'a'); // (3, 3)
+/// ```
+#[macro_export]
+macro_rules! slice_longest_run {
+    ($slice:expr, $value:expr) => {
+        $crate::__internal::SliceRef($slice)
+            .as_slice()
+            .longest_run($value)
+    };
+}
+
+/// Find the start index of each run of consecutive equal elements in a slice, returning a
+/// `([usize; N], usize)` tuple with the start indices and the number of runs. The array has the
+/// same length as the slice, and the entries after the number of runs are zero. The slice must
+/// be a constant, as the length of the output array is computed at compile time. This only
+/// works for slices of primitive integer types, `char` and `bool`.
+///
+/// ```rust
+/// # use const_it::slice_run_boundaries;
+/// const RUNS: ([usize; 6], usize) = slice_run_boundaries!(&[1i32, 1, 2, 2, 2, 3]); // ([0, 2, 5, 0, 0, 0], 3)
+/// ```
+#[macro_export]
+macro_rules! slice_run_boundaries {
+    ($slice:expr) => {{
+        const LEN: ::core::primitive::usize = $slice.len();
+        $crate::__internal::SliceRef($slice)
+            .as_slice()
+            .run_boundaries::<LEN>()
+    }};
+}
+
+/// Collect the unique values of a slice into an array with capacity for `cap` values, in the
+/// order of their first occurrence. Returns `Result<([T; cap], usize), &'static str>` with the
+/// array and the number of unique values, or an error if there are more than `cap` unique
+/// values. The entries after the unique values are zero (`'\0'` for `char` and `false` for
+/// `bool`). This only works for slices of primitive integer types, `char` and `bool`.
+///
+/// ```rust
+/// # use const_it::slice_to_unique_array;
+/// const UNIQUE: Result<([i32; 4], usize), &str> = slice_to_unique_array!(&[1i32, 2, 2, 3, 1], 4); // Ok(([1, 2, 3, 0], 3))
+/// const TOO_MANY: Result<([i32; 2], usize), &str> = slice_to_unique_array!(&[1i32, 2, 3], 2); // Err(_)
+/// ```
+#[macro_export]
+macro_rules! slice_to_unique_array {
+    ($slice:expr, $cap:expr) => {
+        $crate::__internal::SliceRef($slice)
+            .as_slice()
+            .to_unique_array::<{ $cap }>()
+    };
+}
+
+/// Get the run of elements at the start of a slice that are equal to a value, like
+/// `take_while` with an equality test. This only works for slices of primitive integer types,
+/// `char` and `bool`.
+///
+/// ```rust
+/// # use const_it::slice_take_while_eq;
+/// const INDENT: &[u8] = slice_take_while_eq!(b"   x", b' '); // b"   "
+/// ```
+///
+/// See also [`slice_skip_while_eq!`] and [`slice_count_leading!`].
+#[macro_export]
+macro_rules! slice_take_while_eq {
+    ($slice:expr, $value:expr) => {
+        $crate::__internal::SliceRef($slice)
+            .as_slice()
+            .take_while_eq($value)
+    };
+}
+
+/// Get the rest of a slice after the run of elements at the start that are equal to a value,
+/// like `skip_while` with an equality test. This is the same as [`slice_trim_start_matches!`].
+/// This only works for slices of primitive integer types, `char` and `bool`.
+///
+/// ```rust
+/// # use const_it::slice_skip_while_eq;
+/// const REST: &[u8] = slice_skip_while_eq!(b"   x", b' '); // b"x"
+/// ```
+///
+/// See also [`slice_take_while_eq!`].
+#[macro_export]
+macro_rules! slice_skip_while_eq {
+    ($slice:expr, $value:expr) => {
+        $crate::slice_trim_start_matches!($slice, $value)
+    };
+}
+
+/// Check if a slice consists of a pattern repeated a whole number of times. An empty slice is
+/// a repetition of any pattern, and only an empty slice is a repetition of an empty pattern.
+/// This only works for slices of primitive integer types, `char`, `bool` and `str`.
+///
+/// ```rust
+/// # use const_it::slice_is_repetition_of;
+/// const TILED: bool = slice_is_repetition_of!(b"abab", b"ab"); // true
+/// const PARTIAL: bool = slice_is_repetition_of!(b"ababa", b"ab"); // false
+/// ```
+#[macro_export]
+macro_rules! slice_is_repetition_of {
+    ($slice:expr, $pattern:expr) => {
+        $crate::__internal::SliceRef($slice)
+            .as_slice()
+            .is_repetition_of($crate::__internal::SliceRef($pattern).as_slice())
+    };
+}
+
+/// Check if all elements of a slice are equal. Empty slices and slices with a single element
+/// are uniform. This only works for slices of primitive integer types, `char` and `bool`.
+///
+/// ```rust
+/// # use const_it::slice_is_uniform;
+/// const UNIFORM: bool = slice_is_uniform!(&[0xffu8; 16]); // true
+/// ```
+#[macro_export]
+macro_rules! slice_is_uniform {
+    ($slice:expr) => {
+        $crate::__internal::SliceRef($slice).as_slice().is_uniform()
+    };
+}
+
+/// Takes a `Result` and returns the unwrapped `Ok` value, or panics if it's `Err`.
+/// The second argument is the message to use on panic. If the panic message
+/// is omitted, the `Err` value must be of type `&str` and is used as the panic message.
+///
+/// See also [`expect_some!`] and [`unwrap_ok!`].
+#[macro_export]
+macro_rules! expect_ok {
+    ($expr:expr) => {
+        match $expr {
+            ::core::result::Result::Ok(value) => value,
+            ::core::result::Result::Err(err) => panic!("{}", err),
+        }
+    };
+
+    ($expr:expr, $message:expr) => {
+        match $expr {
+            ::core::result::Result::Ok(value) => value,
+            ::core::result::Result::Err(_) => panic!("{}", $message),
+        }
+    };
+}
+
+/// Takes an `Option` and returns the unwrapped `Some` value, or panics if it's `None`.
+/// The second argument is the message to use on panic.
+///
+/// See also [`expect_ok!`] and [`unwrap_some!`].
+#[macro_export]
+macro_rules! expect_some {
+    ($expr:expr, $message:expr) => {
+        match $expr {
+            ::core::option::Option::Some(value) => value,
+            ::core::option::Option::None => panic!("{}", $message),
+        }
+    };
+}
+
+/// Takes a `Result` and returns the unwrapped `Ok` value, or panics if it's `Err`.
+///
+/// See also [`unwrap_some!`] and [`expect_ok!`].
+#[macro_export]
+macro_rules! unwrap_ok {
+    ($expr:expr) => {
+        $crate::expect_ok!($expr, "unwrapped Err value")
+    };
+}
+
+/// Takes an `Option` and returns the unwrapped `Some` value, or panics if it's `None`.
+///
+/// See also [`unwrap_ok!`] and [`expect_some!`].
+#[macro_export]
+macro_rules! unwrap_some {
+    ($expr:expr) => {
+        $crate::expect_some!($expr, "unwrapped None value")
+    };
+}
+
+/// Takes a `Result` and evaluates to the unwrapped `Ok` value, or if it's `Err`, returns the `Err`
+/// to the current function's caller.
+///
+/// See also [`unwrap_some_or_return!`].
+#[macro_export]
+macro_rules! unwrap_ok_or_return {
+    ($expr:expr) => {
+        match $expr {
+            ::core::result::Result::Ok(value) => value,
+            ::core::result::Result::Err(err) => return ::core::result::Result::Err(err),
+        }
+    };
+}
+
+/// Takes an `Option` and evaluates to the unwrapped `Some` value, or if it's `None`, returns the `None`
+/// to the current function's caller.
+///
+/// See also [`unwrap_ok_or_return!`].
+#[macro_export]
+macro_rules! unwrap_some_or_return {
+    ($expr:expr) => {
+        match $expr {
+            ::core::option::Option::Some(value) => value,
+            ::core::option::Option::None => return ::core::option::Option::None,
+        }
+    };
+}
+
+mod array;
+mod error;
+mod iter;
+mod num;
+pub mod ops;
+mod slice;
+mod text;
+
+pub use error::SliceError;
+pub use iter::{ConstChunksExact, ConstLinesInclusive, ConstRArrayChunks, ConstSplitWhitespace};
+pub use slice::{Chars, FromEnd, IntRange, Last, Slice, SliceIndex, SliceRef};
+pub use text::StrBuf;
+
+#[doc(hidden)]
 pub mod __internal {
-    pub use super::slice::{Slice, SliceIndex, SliceRef, SliceTypeCheck};
+    pub use super::array::{
+        array_from, chunks_array, concat, get_many, interleave, pad_end, pad_start, ArrayMap,
+    };
+    pub use super::num::{
+        ascii_to_digit, decimal_as_str, digit_to_ascii, i64_to_decimal, u64_to_decimal, FromBytes,
+    };
+    pub use super::slice::{ratio_index, Slice, SliceArg, SliceIndex, SliceRef, SliceTypeCheck};
+    pub use super::text::{
+        capitalize_ascii, chars_eq, concat_str, count_char, find_char, is_ascii_identifier,
+        join_str, line_at, lines_count, pad_end_ascii, pad_end_str, reverse_ascii, rfind_char,
+        try_reverse_ascii,
+    };
 }
 
 #[cfg(test)]
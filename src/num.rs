@@ -0,0 +1,244 @@
+use crate::slice::SliceRef;
+use core::{marker::PhantomData, str};
+
+/// Get the numeric value of an ASCII digit in the given radix, or `None` if the byte
+/// isn't a valid digit. Letters are accepted in both cases.
+pub const fn ascii_to_digit(byte: u8, radix: u32) -> Option<u8> {
+    assert!(
+        radix >= 2 && radix <= 36,
+        "radix must be in the range 2..=36"
+    );
+    let value = match byte {
+        b'0'..=b'9' => byte - b'0',
+        b'a'..=b'z' => byte - b'a' + 10,
+        b'A'..=b'Z' => byte - b'A' + 10,
+        _ => return None,
+    };
+    if (value as u32) < radix {
+        Some(value)
+    } else {
+        None
+    }
+}
+
+/// Get the lowercase ASCII digit for a numeric value in the given radix, or `None` if
+/// the value is out of range for the radix.
+pub const fn digit_to_ascii(value: u8, radix: u32) -> Option<u8> {
+    assert!(
+        radix >= 2 && radix <= 36,
+        "radix must be in the range 2..=36"
+    );
+    if value as u32 >= radix {
+        None
+    } else if value < 10 {
+        Some(b'0' + value)
+    } else {
+        Some(b'a' + value - 10)
+    }
+}
+
+macro_rules! impl_slice_to_bytes {
+    ($($t:ty),* $(,)?) => { $(
+        impl<'a> SliceRef<'a, [$t]> {
+            pub const fn byte_len(self) -> usize {
+                self.0.len() * core::mem::size_of::<$t>()
+            }
+
+            pub const fn to_le_bytes<const N: usize>(self) -> [u8; N] {
+                const WIDTH: usize = core::mem::size_of::<$t>();
+                assert!(
+                    self.0.len() * WIDTH == N,
+                    "output length must be the byte length of the slice"
+                );
+                let mut out = [0; N];
+                let mut i = 0;
+                while i < self.0.len() {
+                    let bytes = self.0[i].to_le_bytes();
+                    let mut j = 0;
+                    while j < WIDTH {
+                        out[i * WIDTH + j] = bytes[j];
+                        j += 1;
+                    }
+                    i += 1;
+                }
+                out
+            }
+
+            pub const fn to_be_bytes<const N: usize>(self) -> [u8; N] {
+                const WIDTH: usize = core::mem::size_of::<$t>();
+                assert!(
+                    self.0.len() * WIDTH == N,
+                    "output length must be the byte length of the slice"
+                );
+                let mut out = [0; N];
+                let mut i = 0;
+                while i < self.0.len() {
+                    let bytes = self.0[i].to_be_bytes();
+                    let mut j = 0;
+                    while j < WIDTH {
+                        out[i * WIDTH + j] = bytes[j];
+                        j += 1;
+                    }
+                    i += 1;
+                }
+                out
+            }
+        }
+    )* };
+}
+
+impl_slice_to_bytes!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// Conversion of byte slices to arrays of the primitive integer type `T`.
+pub struct FromBytes<T>(PhantomData<T>);
+
+macro_rules! impl_from_bytes {
+    ($($t:ty),* $(,)?) => { $(
+        impl FromBytes<$t> {
+            #[allow(clippy::modulo_one)]
+            const fn from_bytes<const N: usize>(bytes: &[u8], le: bool) -> Option<[$t; N]> {
+                const WIDTH: usize = core::mem::size_of::<$t>();
+                if bytes.len() % WIDTH != 0 {
+                    return None;
+                }
+                assert!(
+                    bytes.len() / WIDTH == N,
+                    "output length must be the number of values in the slice"
+                );
+                let mut out = [0; N];
+                let mut i = 0;
+                while i < N {
+                    let mut value = [0; WIDTH];
+                    let mut j = 0;
+                    while j < WIDTH {
+                        value[j] = bytes[i * WIDTH + j];
+                        j += 1;
+                    }
+                    out[i] = if le {
+                        <$t>::from_le_bytes(value)
+                    } else {
+                        <$t>::from_be_bytes(value)
+                    };
+                    i += 1;
+                }
+                Some(out)
+            }
+
+            pub const fn from_le_bytes<const N: usize>(bytes: &[u8]) -> Option<[$t; N]> {
+                Self::from_bytes(bytes, true)
+            }
+
+            pub const fn from_be_bytes<const N: usize>(bytes: &[u8]) -> Option<[$t; N]> {
+                Self::from_bytes(bytes, false)
+            }
+        }
+    )* };
+}
+
+impl_from_bytes!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_slice_count_bits {
+    ($($t:ty),* $(,)?) => { $(
+        impl<'a> SliceRef<'a, [$t]> {
+            pub const fn count_ones(self) -> usize {
+                let mut count = 0;
+                let mut i = 0;
+                while i < self.0.len() {
+                    count += self.0[i].count_ones() as usize;
+                    i += 1;
+                }
+                count
+            }
+
+            pub const fn count_zeros(self) -> usize {
+                let mut count = 0;
+                let mut i = 0;
+                while i < self.0.len() {
+                    count += self.0[i].count_zeros() as usize;
+                    i += 1;
+                }
+                count
+            }
+        }
+    )* };
+}
+
+impl_slice_count_bits!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// Write the decimal digits of `value` to the end of an array, returning the array and the
+/// index of the first digit
+pub const fn u64_to_decimal(value: u64) -> ([u8; 20], usize) {
+    let mut out = [0; 20];
+    let mut value = value;
+    let mut start = out.len();
+    loop {
+        start -= 1;
+        out[start] = b'0' + (value % 10) as u8;
+        value /= 10;
+        if value == 0 {
+            return (out, start);
+        }
+    }
+}
+
+/// Write the decimal digits of `value` with a leading `-` if it's negative to the end of an
+/// array, returning the array and the index of the first byte
+pub const fn i64_to_decimal(value: i64) -> ([u8; 21], usize) {
+    // unsigned_abs doesn't overflow for i64::MIN
+    let (digits, start) = u64_to_decimal(value.unsigned_abs());
+    let mut out = [0; 21];
+    let mut i = start;
+    while i < digits.len() {
+        out[i + 1] = digits[i];
+        i += 1;
+    }
+    if value < 0 {
+        out[start] = b'-';
+        (out, start)
+    } else {
+        (out, start + 1)
+    }
+}
+
+/// Get the digits written by a decimal formatting function as a `str`.
+/// Panics if `start` is out of range.
+pub const fn decimal_as_str<const N: usize>(digits: &([u8; N], usize)) -> &str {
+    let (_, digits) = digits.0.split_at(digits.1);
+    match str::from_utf8(digits) {
+        Ok(s) => s,
+        Err(_) => panic!("decimal digits must be ascii"),
+    }
+}
+
+macro_rules! impl_slice_float {
+    ($($t:ty),* $(,)?) => { $(
+        impl<'a> SliceRef<'a, [$t]> {
+            pub const fn sum_float(self) -> $t {
+                // -0.0 is the additive identity, so the sum of `[-0.0]` is `-0.0`
+                let mut sum = -0.0;
+                let mut i = 0;
+                while i < self.0.len() {
+                    sum += self.0[i];
+                    i += 1;
+                }
+                sum
+            }
+
+            pub const fn bits_eq(self, other: SliceRef<[$t]>) -> bool {
+                if self.0.len() != other.0.len() {
+                    return false;
+                }
+                let mut i = 0;
+                while i < self.0.len() {
+                    if self.0[i].to_bits() != other.0[i].to_bits() {
+                        return false;
+                    }
+                    i += 1;
+                }
+                true
+            }
+        }
+    )* };
+}
+
+impl_slice_float!(f32, f64);
@@ -0,0 +1,151 @@
+//! `const fn` versions of some of the macros for byte slices and `str`s.
+//!
+//! These can be used where a macro is awkward, for example when passing the operation
+//! as a function argument.
+//!
+//! ```rust
+//! use const_it::ops;
+//! const EQ: bool = ops::eq_str("const", "const"); // true
+//! const REST: Option<&[u8]> = ops::strip_prefix_bytes(b"key=value", b"key="); // Some(b"value")
+//! ```
+
+use crate::slice::SliceRef;
+use core::{cmp::Ordering, str};
+
+/// Compare two byte slices lexicographically, like [`slice_cmp!`](crate::slice_cmp).
+pub const fn cmp_bytes(a: &[u8], b: &[u8]) -> Ordering {
+    let len = if a.len() < b.len() { a.len() } else { b.len() };
+    let mut i = 0;
+    while i < len {
+        if a[i] < b[i] {
+            return Ordering::Less;
+        } else if a[i] > b[i] {
+            return Ordering::Greater;
+        }
+        i += 1;
+    }
+    if a.len() < b.len() {
+        Ordering::Less
+    } else if a.len() > b.len() {
+        Ordering::Greater
+    } else {
+        Ordering::Equal
+    }
+}
+
+/// Compare two `str`s lexicographically, like [`slice_cmp!`](crate::slice_cmp).
+pub const fn cmp_str(a: &str, b: &str) -> Ordering {
+    cmp_bytes(a.as_bytes(), b.as_bytes())
+}
+
+/// Compare two nul-terminated byte strings up to their first nul byte, like
+/// [`cstr_cmp!`](crate::cstr_cmp). A slice without a nul byte is compared in full.
+pub const fn cmp_cstr(a: &[u8], b: &[u8]) -> Ordering {
+    cmp_bytes(until_nul(a), until_nul(b))
+}
+
+const fn until_nul(s: &[u8]) -> &[u8] {
+    match SliceRef(s).position(0) {
+        Some(len) => s.split_at(len).0,
+        None => s,
+    }
+}
+
+/// Check if two byte slices are equal, like [`slice_eq!`](crate::slice_eq).
+pub const fn eq_bytes(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Check if two byte slices are equal, like [`slice_fast_eq!`](crate::slice_fast_eq).
+/// This compares eight bytes at a time.
+pub const fn fast_eq_bytes(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let (mut a, mut b) = (a, b);
+    while let (Some((word_a, rest_a)), Some((word_b, rest_b))) =
+        (a.split_first_chunk::<8>(), b.split_first_chunk::<8>())
+    {
+        if u64::from_ne_bytes(*word_a) != u64::from_ne_bytes(*word_b) {
+            return false;
+        }
+        a = rest_a;
+        b = rest_b;
+    }
+    eq_bytes(a, b)
+}
+
+/// Check if two `str`s are equal, like [`slice_eq!`](crate::slice_eq).
+pub const fn eq_str(a: &str, b: &str) -> bool {
+    eq_bytes(a.as_bytes(), b.as_bytes())
+}
+
+/// Strip a prefix from a byte slice, like [`slice_strip_prefix!`](crate::slice_strip_prefix).
+pub const fn strip_prefix_bytes<'a>(s: &'a [u8], prefix: &[u8]) -> Option<&'a [u8]> {
+    if s.len() < prefix.len() {
+        return None;
+    }
+    let (head, rest) = s.split_at(prefix.len());
+    if eq_bytes(head, prefix) {
+        Some(rest)
+    } else {
+        None
+    }
+}
+
+/// Strip a prefix from a `str`, like [`slice_strip_prefix!`](crate::slice_strip_prefix).
+pub const fn strip_prefix_str<'a>(s: &'a str, prefix: &str) -> Option<&'a str> {
+    match strip_prefix_bytes(s.as_bytes(), prefix.as_bytes()) {
+        // safety: `s` starts with all of `prefix`, so the rest starts on a codepoint boundary
+        Some(rest) => Some(unsafe { str::from_utf8_unchecked(rest) }),
+        None => None,
+    }
+}
+
+/// Strip a suffix from a byte slice, like [`slice_strip_suffix!`](crate::slice_strip_suffix).
+pub const fn strip_suffix_bytes<'a>(s: &'a [u8], suffix: &[u8]) -> Option<&'a [u8]> {
+    if s.len() < suffix.len() {
+        return None;
+    }
+    let (rest, tail) = s.split_at(s.len() - suffix.len());
+    if eq_bytes(tail, suffix) {
+        Some(rest)
+    } else {
+        None
+    }
+}
+
+/// Strip a suffix from a `str`, like [`slice_strip_suffix!`](crate::slice_strip_suffix).
+pub const fn strip_suffix_str<'a>(s: &'a str, suffix: &str) -> Option<&'a str> {
+    match strip_suffix_bytes(s.as_bytes(), suffix.as_bytes()) {
+        // safety: `s` ends with all of `suffix`, so the rest ends on a codepoint boundary
+        Some(rest) => Some(unsafe { str::from_utf8_unchecked(rest) }),
+        None => None,
+    }
+}
+
+/// Check if a byte slice matches a pattern of equal length, where `wildcard` bytes in the
+/// pattern match any byte, like [`slice_eq_wildcard!`](crate::slice_eq_wildcard).
+pub const fn eq_wildcard_bytes(s: &[u8], pattern: &[u8], wildcard: u8) -> bool {
+    if s.len() != pattern.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < s.len() {
+        if pattern[i] != wildcard && pattern[i] != s[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
@@ -1,9 +1,22 @@
+use crate::{error::panic_start_greater_than_end, ops, SliceError};
 use core::{
     cmp::Ordering,
+    mem::MaybeUninit,
     ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive},
-    str,
+    ptr, str,
 };
 
+/// Like `expect_ok!`, but for `Result<_, SliceError>`, whose `Display` impl can't be used in
+/// a const context
+macro_rules! expect_slice_ok {
+    ($expr:expr) => {
+        match $expr {
+            Ok(value) => value,
+            Err(err) => panic!("{}", err.message()),
+        }
+    };
+}
+
 pub trait Sealed {
     fn __not_object_safe<T>() {}
 }
@@ -50,8 +63,118 @@ impl_si!(
     RangeInclusive<usize>,
     RangeTo<usize>,
     RangeToInclusive<usize>,
+    (usize, usize),
 );
 
+/// Slice index for the last `n` elements of a slice, for use with [`slice!`] and [`try_slice!`].
+/// For `str`, `n` is a length in bytes.
+///
+/// Like the other index types, this is an error if `n` is greater than the length of the
+/// slice, or for `str`, if the start of the resulting slice would split a unicode codepoint.
+///
+/// ```rust
+/// # use const_it::{slice, Last};
+/// const BYTES: &[u8] = slice!(b"01234", Last(2)); // b"34"
+/// const STR: &str = slice!("const slice", Last(5)); // "slice"
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Last(pub usize);
+
+impl Sealed for Last {}
+
+impl SliceIndex<str> for Last {
+    type Output = str;
+}
+
+impl<T> SliceIndex<[T]> for Last {
+    type Output = [T];
+}
+
+impl<T, const N: usize> SliceIndex<[T; N]> for Last {
+    type Output = [T];
+}
+
+/// Slice index for a range measured from the end of a slice, for use with [`slice!`] and
+/// [`try_slice!`]. `FromEnd(start..end)` is equivalent to `len - end..len - start`, so
+/// `FromEnd(0..2)` is the last two elements. For `str`, the range is in bytes.
+///
+/// ```rust
+/// # use const_it::{slice, FromEnd};
+/// const BYTES: &[u8] = slice!(b"01234", FromEnd(1..3)); // b"23"
+/// const STR: &str = slice!("const slice", FromEnd(0..5)); // "slice"
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FromEnd(pub Range<usize>);
+
+impl Sealed for FromEnd {}
+
+impl SliceIndex<str> for FromEnd {
+    type Output = str;
+}
+
+impl<T> SliceIndex<[T]> for FromEnd {
+    type Output = [T];
+}
+
+impl<T, const N: usize> SliceIndex<[T; N]> for FromEnd {
+    type Output = [T];
+}
+
+/// Slice index for a range of `char` positions in a `str`, for use with [`slice!`] and
+/// [`try_slice!`]. `Chars(1..3)` is the second and third `char` of the string. Finding the
+/// byte offsets takes time proportional to the end of the range.
+///
+/// Like the other index types, this is an error if the start is higher than the end, or if
+/// the end is greater than the number of `char`s in the string.
+///
+/// ```rust
+/// # use const_it::{slice, Chars};
+/// const STR: &str = slice!("✨💖ab", Chars(1..3)); // "💖a"
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Chars(pub Range<usize>);
+
+impl Sealed for Chars {}
+
+impl SliceIndex<str> for Chars {
+    type Output = str;
+}
+
+/// Slice index for a `Range<u16>`, `Range<u32>` or `Range<i32>`, for use with [`slice!`] and
+/// [`try_slice!`]. The bounds are converted to `usize`, and negative bounds are out of range.
+///
+/// Ranges of these types can't be used as slice indices directly, because then the type of a
+/// range of untyped integer literals like `1..3` would be ambiguous.
+///
+/// ```rust
+/// # use const_it::{slice, IntRange};
+/// const START: u32 = 1;
+/// const END: u32 = 3;
+/// const BYTES: &[u8] = slice!(b"01234", IntRange(START..END)); // b"12"
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct IntRange<T>(pub Range<T>);
+
+macro_rules! impl_int_range {
+    ($($t:ty),* $(,)?) => { $(
+        impl Sealed for IntRange<$t> {}
+
+        impl SliceIndex<str> for IntRange<$t> {
+            type Output = str;
+        }
+
+        impl<T> SliceIndex<[T]> for IntRange<$t> {
+            type Output = [T];
+        }
+
+        impl<T, const N: usize> SliceIndex<[T; N]> for IntRange<$t> {
+            type Output = [T];
+        }
+    )* };
+}
+
+impl_int_range!(u16, u32, i32);
+
 pub struct SliceTypeCheck<'a, S: ?Sized, Index: SliceIndex<S>>(pub &'a S, pub Index);
 
 /// A pending slice operation. This can be used to slice `&[T]` and `&str` in a const context
@@ -59,16 +182,38 @@ pub struct SliceTypeCheck<'a, S: ?Sized, Index: SliceIndex<S>>(pub &'a S, pub In
 ///
 /// You can use the [`slice!`], [`try_slice!`], [`split_slice_at!`] and [`try_split_slice_at!`]
 /// convenience macros instead of using this directly.
+///
+/// To slice the same item several times, create a `Slice` without an index with
+/// [`Slice::new`] and add an index for each operation with [`Slice::at`].
+///
+/// ```rust
+/// # use const_it::Slice;
+/// const SRC: Slice<str, ()> = Slice::new("const slice");
+/// const CONST: &str = SRC.at(..5).index(); // "const"
+/// const SLICE: Option<&str> = SRC.at(6..).get(); // Some("slice")
+/// ```
 pub struct Slice<'a, S: ?Sized, Index>(pub &'a S, pub Index);
 
-const fn slice<T>(s: &[T], start: usize, end: usize) -> Result<&[T], &'static str> {
+impl<'a, S: ?Sized> Slice<'a, S, ()> {
+    /// Create a slice operation on `s` without an index
+    pub const fn new(s: &'a S) -> Self {
+        Slice(s, ())
+    }
+
+    /// Create a slice operation on the same item with the given index
+    pub const fn at<Index: SliceIndex<S>>(&self, index: Index) -> Slice<'a, S, Index> {
+        Slice(self.0, index)
+    }
+}
+
+const fn slice<T>(s: &[T], start: usize, end: usize) -> Result<&[T], SliceError> {
     let ptr = s.as_ptr();
     let len = s.len();
     if start > end {
-        return Err("slice index start is higher than end");
+        return Err(SliceError::StartGreaterThanEnd);
     }
     if end > len {
-        return Err("slice index out of range");
+        return Err(SliceError::OutOfRange);
     }
     let new_len = end - start;
     Ok(unsafe {
@@ -77,29 +222,23 @@ const fn slice<T>(s: &[T], start: usize, end: usize) -> Result<&[T], &'static st
     })
 }
 
-const fn slice_inclusive<T>(s: &[T], start: usize, end: usize) -> Result<&[T], &'static str> {
-    let ptr = s.as_ptr();
-    let len = s.len();
+const fn slice_inclusive<T>(s: &[T], start: usize, end: usize) -> Result<&[T], SliceError> {
     if start > end {
-        return Err("slice index start is higher than end");
+        return Err(SliceError::StartGreaterThanEnd);
     }
-    if end >= len {
-        return Err("slice index out of range");
+    match end.checked_add(1) {
+        Some(end) => slice(s, start, end),
+        None => Err(SliceError::OutOfRange),
     }
-    let new_len = end - start + 1;
-    Ok(unsafe {
-        // safety: the range has been checked to be valid above
-        core::slice::from_raw_parts(ptr.add(start), new_len)
-    })
 }
 
-const fn str_slice(s: &str, start: usize, end: usize) -> Result<&str, &'static str> {
+const fn str_slice(s: &str, start: usize, end: usize) -> Result<&str, SliceError> {
     let bytes = s.as_bytes();
     let sliced = unwrap_ok_or_return!(slice(bytes, start, end));
     if (start < bytes.len() && bytes[start] & 0xc0 == 0x80)
         || (end < bytes.len() && bytes[end] & 0xc0 == 0x80)
     {
-        return Err("slice splits utf-8 codepoint");
+        return Err(SliceError::SplitsCodepoint);
     }
     Ok(unsafe {
         // safety: the slice was valid utf-8 before and has been checked to not split codepoints
@@ -107,31 +246,197 @@ const fn str_slice(s: &str, start: usize, end: usize) -> Result<&str, &'static s
     })
 }
 
-const fn str_slice_inclusive(s: &str, start: usize, end: usize) -> Result<&str, &'static str> {
+const fn str_slice_inclusive(s: &str, start: usize, end: usize) -> Result<&str, SliceError> {
+    if start > end {
+        return Err(SliceError::StartGreaterThanEnd);
+    }
+    // an inclusive end of usize::MAX is always out of range, and is checked before the
+    // codepoint boundaries so it never reports a split codepoint instead
+    match end.checked_add(1) {
+        Some(end) => str_slice(s, start, end),
+        None => Err(SliceError::OutOfRange),
+    }
+}
+
+const fn signed_range(start: i32, end: i32) -> Result<(usize, usize), SliceError> {
+    if start > end {
+        return Err(SliceError::StartGreaterThanEnd);
+    }
+    if start < 0 {
+        return Err(SliceError::OutOfRange);
+    }
+    Ok((start as usize, end as usize))
+}
+
+const fn slice_signed<T>(s: &[T], start: i32, end: i32) -> Result<&[T], SliceError> {
+    let (start, end) = unwrap_ok_or_return!(signed_range(start, end));
+    slice(s, start, end)
+}
+
+const fn str_slice_signed(s: &str, start: i32, end: i32) -> Result<&str, SliceError> {
+    let (start, end) = unwrap_ok_or_return!(signed_range(start, end));
+    str_slice(s, start, end)
+}
+
+const fn str_slice_chars(s: &str, start: usize, end: usize) -> Result<&str, SliceError> {
+    if start > end {
+        return Err(SliceError::StartGreaterThanEnd);
+    }
     let bytes = s.as_bytes();
-    let sliced = unwrap_ok_or_return!(slice_inclusive(bytes, start, end));
-    if (start < bytes.len() && bytes[start] & 0xc0 == 0x80)
-        || (end < usize::MAX && end + 1 < bytes.len() && bytes[end + 1] & 0xc0 == 0x80)
-    {
-        return Err("slice splits utf-8 codepoint");
+    let (mut byte_start, mut chars) = (0, 0);
+    let mut i = 0;
+    loop {
+        if chars == start {
+            byte_start = i;
+        }
+        if chars == end {
+            break;
+        }
+        if i == bytes.len() {
+            return Err(SliceError::OutOfRange);
+        }
+        i += 1;
+        while i < bytes.len() && bytes[i] & 0xc0 == 0x80 {
+            i += 1;
+        }
+        chars += 1;
     }
-    Ok(unsafe {
-        // safety: the slice was valid utf-8 before and has been checked to not split codepoints
-        str::from_utf8_unchecked(sliced)
-    })
+    str_slice(s, byte_start, i)
+}
+
+const fn slice_from<T>(s: &[T], start: usize) -> Result<&[T], SliceError> {
+    if start > s.len() {
+        return Err(SliceError::OutOfRange);
+    }
+    slice(s, start, s.len())
+}
+
+const fn str_slice_from(s: &str, start: usize) -> Result<&str, SliceError> {
+    if start > s.len() {
+        return Err(SliceError::OutOfRange);
+    }
+    str_slice(s, start, s.len())
+}
+
+const fn slice_last<T>(s: &[T], n: usize) -> Result<&[T], SliceError> {
+    if n > s.len() {
+        return Err(SliceError::OutOfRange);
+    }
+    slice(s, s.len() - n, s.len())
+}
+
+const fn str_slice_last(s: &str, n: usize) -> Result<&str, SliceError> {
+    if n > s.len() {
+        return Err(SliceError::OutOfRange);
+    }
+    str_slice(s, s.len() - n, s.len())
 }
 
+const fn from_end(len: usize, start: usize, end: usize) -> Result<(usize, usize), SliceError> {
+    if start > end {
+        return Err(SliceError::StartGreaterThanEnd);
+    }
+    if end > len {
+        return Err(SliceError::OutOfRange);
+    }
+    Ok((len - end, len - start))
+}
+
+const fn slice_from_end<T>(s: &[T], start: usize, end: usize) -> Result<&[T], SliceError> {
+    let (start, end) = unwrap_ok_or_return!(from_end(s.len(), start, end));
+    slice(s, start, end)
+}
+
+const fn str_slice_from_end(s: &str, start: usize, end: usize) -> Result<&str, SliceError> {
+    let (start, end) = unwrap_ok_or_return!(from_end(s.len(), start, end));
+    str_slice(s, start, end)
+}
+
+type SplitAround<'a, T> = (&'a [T], &'a [T], &'a [T]);
+
+const fn split_around<T>(
+    s: &[T],
+    start: usize,
+    end: usize,
+) -> Result<SplitAround<'_, T>, SliceError> {
+    let within = unwrap_ok_or_return!(slice(s, start, end));
+    Ok((
+        unwrap_ok_or_return!(slice(s, 0, start)),
+        within,
+        unwrap_ok_or_return!(slice(s, end, s.len())),
+    ))
+}
+
+const fn str_split_around(
+    s: &str,
+    start: usize,
+    end: usize,
+) -> Result<(&str, &str, &str), SliceError> {
+    let within = unwrap_ok_or_return!(str_slice(s, start, end));
+    Ok((
+        unwrap_ok_or_return!(str_slice(s, 0, start)),
+        within,
+        unwrap_ok_or_return!(str_slice(s, end, s.len())),
+    ))
+}
+
+const fn slice_clamped<T>(s: &[T], start: usize, end: usize) -> &[T] {
+    let end = if end > s.len() { s.len() } else { end };
+    let start = if start > end { end } else { start };
+    expect_slice_ok!(slice(s, start, end))
+}
+
+const fn str_slice_clamped(s: &str, start: usize, end: usize) -> &str {
+    let bytes = s.as_bytes();
+    let mut end = if end > bytes.len() { bytes.len() } else { end };
+    while end < bytes.len() && bytes[end] & 0xc0 == 0x80 {
+        end -= 1;
+    }
+    let mut start = if start > end { end } else { start };
+    while start < end && bytes[start] & 0xc0 == 0x80 {
+        start += 1;
+    }
+    expect_slice_ok!(str_slice(s, start, end))
+}
+
+/// Get the index `len * num / den`, rounded down. Panics if `den` is zero.
+pub const fn ratio_index(len: usize, num: usize, den: usize) -> usize {
+    assert!(den != 0, "ratio denominator must be non-zero");
+    (len as u128 * num as u128 / den as u128) as usize
+}
+
+/// Implement `checked_get`, `get` and `index` for a slice and index type. If the index can have
+/// a start that's higher than its end, `reversed(start, end)` gives the bounds to include in
+/// the panic message of `index`.
 macro_rules! impl_slice {
-    ($(<$(@[$($gen:tt)*])? $slice:ty, $index:ty> $self:ident $imp:block)*) => { $(
+    ($(
+        <$(@[$($gen:tt)*])? $slice:ty, $index:ty> $self:ident $imp:block
+        $(reversed($($bounds:tt)*))?
+    )*) => { $(
         impl<'a $(, $($gen)*)?> Slice<'a, $slice, $index> {
+            /// Evaluate this slice operation, or return the reason for the error
+            pub const fn checked_get(
+                &$self,
+            ) -> Result<&'a <$index as SliceIndex<$slice>>::Output, SliceError> {
+                $imp
+            }
+
             /// Evaluate this slice operation, or return `None` on error
-            pub const fn get(&$self) -> Option<&'a <$index as SliceIndex<$slice>>::Output> {
-                ok!($imp)
+            pub const fn get(&self) -> Option<&'a <$index as SliceIndex<$slice>>::Output> {
+                ok!(self.checked_get())
             }
 
             /// Evaluate this slice operation, or panic on error
+            #[track_caller]
             pub const fn index(&$self) -> &'a <$index as SliceIndex<$slice>>::Output {
-                expect_ok!($imp)
+                match $self.checked_get() {
+                    Ok(value) => value,
+                    $(Err(SliceError::StartGreaterThanEnd) => {
+                        let (start, end) = ($($bounds)*);
+                        panic_start_greater_than_end(start as i128, end as i128)
+                    })?
+                    Err(err) => panic!("{}", err.message()),
+                }
             }
         }
     )* };
@@ -139,38 +444,103 @@ macro_rules! impl_slice {
 
 impl<'a, T> Slice<'a, [T], usize> {
     /// Split the slice at the stored index, or panic on error
+    #[track_caller]
     pub const fn split(&self) -> (&'a [T], &'a [T]) {
         self.0.split_at(self.1)
     }
 
     /// Split the slice at the stored index, or return `None` on error
     pub const fn try_split(&self) -> Option<(&'a [T], &'a [T])> {
+        ok!(self.checked_split())
+    }
+
+    /// Split the slice at the stored index, or return the reason for the error
+    pub const fn checked_split(&self) -> Result<(&'a [T], &'a [T]), SliceError> {
         if self.1 <= self.0.len() {
-            Some(self.split())
+            Ok(self.split())
         } else {
-            None
+            Err(SliceError::OutOfRange)
         }
     }
 }
 
 impl<'a, T, const N: usize> Slice<'a, [T; N], usize> {
     /// Split the slice at the stored index, or panic on error
+    #[track_caller]
     pub const fn split(&self) -> (&'a [T], &'a [T]) {
         self.0.split_at(self.1)
     }
 
     /// Split the slice at the stored index, or return `None` on error
     pub const fn try_split(&self) -> Option<(&'a [T], &'a [T])> {
+        ok!(self.checked_split())
+    }
+
+    /// Split the slice at the stored index, or return the reason for the error
+    pub const fn checked_split(&self) -> Result<(&'a [T], &'a [T]), SliceError> {
         if self.1 <= self.0.len() {
-            Some(self.split())
+            Ok(self.split())
+        } else {
+            Err(SliceError::OutOfRange)
+        }
+    }
+}
+
+impl<'a, T: Copy> Slice<'a, [T], usize> {
+    /// Get a copy of the element at the stored index, or panic if it's out of range
+    #[track_caller]
+    pub const fn copy(&self) -> T {
+        self.0[self.1]
+    }
+
+    /// Get a copy of the element at the stored index, or `None` if it's out of range
+    pub const fn try_copy(&self) -> Option<T> {
+        if self.1 < self.0.len() {
+            Some(self.0[self.1])
+        } else {
+            None
+        }
+    }
+
+    /// Get a copy of the element at the stored index, or `default` if it's out of range
+    pub const fn get_or(&self, default: T) -> T {
+        if self.1 < self.0.len() {
+            self.0[self.1]
+        } else {
+            default
+        }
+    }
+}
+
+impl<'a, T: Copy, const N: usize> Slice<'a, [T; N], usize> {
+    /// Get a copy of the element at the stored index, or panic if it's out of range
+    #[track_caller]
+    pub const fn copy(&self) -> T {
+        self.0[self.1]
+    }
+
+    /// Get a copy of the element at the stored index, or `None` if it's out of range
+    pub const fn try_copy(&self) -> Option<T> {
+        if self.1 < self.0.len() {
+            Some(self.0[self.1])
         } else {
             None
         }
     }
+
+    /// Get a copy of the element at the stored index, or `default` if it's out of range
+    pub const fn get_or(&self, default: T) -> T {
+        if self.1 < N {
+            self.0[self.1]
+        } else {
+            default
+        }
+    }
 }
 
 impl<'a> Slice<'a, str, usize> {
     /// Split the slice at the stored index, or panic on error
+    #[track_caller]
     pub const fn split(&self) -> (&'a str, &'a str) {
         expect_some!(
             self.try_split(),
@@ -180,11 +550,16 @@ impl<'a> Slice<'a, str, usize> {
 
     /// Split the slice at the stored index, or return `None` on error
     pub const fn try_split(&self) -> Option<(&'a str, &'a str)> {
-        let (a, b) = unwrap_some_or_return!(Slice(self.0.as_bytes(), self.1).try_split());
-        if b[0] & 0xc0 == 0x80 {
-            None
+        ok!(self.checked_split())
+    }
+
+    /// Split the slice at the stored index, or return the reason for the error
+    pub const fn checked_split(&self) -> Result<(&'a str, &'a str), SliceError> {
+        let (a, b) = unwrap_ok_or_return!(Slice(self.0.as_bytes(), self.1).checked_split());
+        if !b.is_empty() && b[0] & 0xc0 == 0x80 {
+            Err(SliceError::SplitsCodepoint)
         } else {
-            Some(unsafe {
+            Ok(unsafe {
                 // safety: split wasn't in the middle of a codepoint
                 (str::from_utf8_unchecked(a), str::from_utf8_unchecked(b))
             })
@@ -192,57 +567,108 @@ impl<'a> Slice<'a, str, usize> {
     }
 }
 
+impl<'a, T> Slice<'a, [T], Range<usize>> {
+    /// Split the slice around the stored range, or panic on error
+    #[track_caller]
+    pub const fn split_around(&self) -> (&'a [T], &'a [T], &'a [T]) {
+        expect_slice_ok!(split_around(self.0, self.1.start, self.1.end))
+    }
+
+    /// Split the slice around the stored range, or return `None` on error
+    pub const fn try_split_around(&self) -> Option<(&'a [T], &'a [T], &'a [T])> {
+        ok!(split_around(self.0, self.1.start, self.1.end))
+    }
+}
+
+impl<'a, T, const N: usize> Slice<'a, [T; N], Range<usize>> {
+    /// Split the slice around the stored range, or panic on error
+    #[track_caller]
+    pub const fn split_around(&self) -> (&'a [T], &'a [T], &'a [T]) {
+        expect_slice_ok!(split_around(self.0, self.1.start, self.1.end))
+    }
+
+    /// Split the slice around the stored range, or return `None` on error
+    pub const fn try_split_around(&self) -> Option<(&'a [T], &'a [T], &'a [T])> {
+        ok!(split_around(self.0, self.1.start, self.1.end))
+    }
+}
+
+impl<'a> Slice<'a, str, Range<usize>> {
+    /// Split the slice around the stored range, or panic on error
+    #[track_caller]
+    pub const fn split_around(&self) -> (&'a str, &'a str, &'a str) {
+        expect_slice_ok!(str_split_around(self.0, self.1.start, self.1.end))
+    }
+
+    /// Split the slice around the stored range, or return `None` on error
+    pub const fn try_split_around(&self) -> Option<(&'a str, &'a str, &'a str)> {
+        ok!(str_split_around(self.0, self.1.start, self.1.end))
+    }
+}
+
 impl_slice! {
-    <@[T] [T], usize> self { Ok::<_, &'static str>(&self.0[self.1]) }
+    <@[T] [T], usize> self {
+        if self.1 < self.0.len() {
+            Ok(&self.0[self.1])
+        } else {
+            Err(SliceError::OutOfRange)
+        }
+    }
 
-    <@[T, const N: usize] [T; N], usize> self { Ok::<_, &'static str>(&self.0[self.1]) }
+    <@[T, const N: usize] [T; N], usize> self {
+        if self.1 < N {
+            Ok(&self.0[self.1])
+        } else {
+            Err(SliceError::OutOfRange)
+        }
+    }
 
     <@[T] [T], Range<usize>> self {
         slice(self.0, self.1.start, self.1.end)
-    }
+    } reversed(self.1.start, self.1.end)
 
     <@[T, const N: usize] [T; N], Range<usize>> self {
         slice(self.0, self.1.start, self.1.end)
-    }
+    } reversed(self.1.start, self.1.end)
 
     <str, Range<usize>> self {
         str_slice(self.0, self.1.start, self.1.end)
-    }
+    } reversed(self.1.start, self.1.end)
 
     <@[T] [T], RangeInclusive<usize>> self {
         slice_inclusive(self.0, *self.1.start(), *self.1.end())
-    }
+    } reversed(*self.1.start(), *self.1.end())
 
     <@[T, const N: usize] [T; N], RangeInclusive<usize>> self {
         slice_inclusive(self.0, *self.1.start(), *self.1.end())
-    }
+    } reversed(*self.1.start(), *self.1.end())
 
     <str, RangeInclusive<usize>> self {
         str_slice_inclusive(self.0, *self.1.start(), *self.1.end())
-    }
+    } reversed(*self.1.start(), *self.1.end())
 
     <@[T] [T], RangeFrom<usize>> self {
-        slice(self.0, self.1.start, self.0.len())
+        slice_from(self.0, self.1.start)
     }
 
     <@[T, const N: usize] [T; N], RangeFrom<usize>> self {
-        slice(self.0, self.1.start, self.0.len())
+        slice_from(self.0, self.1.start)
     }
 
     <str, RangeFrom<usize>> self {
-        str_slice(self.0, self.1.start, self.0.len())
+        str_slice_from(self.0, self.1.start)
     }
 
     <@[T] [T], RangeFull> self {
-        Ok::<_, &'static str>(self.0)
+        Ok(self.0)
     }
 
     <@[T, const N: usize] [T; N], RangeFull> self {
-        Ok::<_, &'static str>(self.0)
+        Ok(self.0)
     }
 
     <str, RangeFull> self {
-        Ok::<_, &'static str>(self.0)
+        Ok(self.0)
     }
 
     <@[T] [T], RangeTo<usize>> self {
@@ -268,89 +694,1001 @@ impl_slice! {
     <str, RangeToInclusive<usize>> self {
         str_slice_inclusive(self.0, 0, self.1.end)
     }
-}
 
-pub struct SliceRef<'a, T: ?Sized>(pub &'a T);
+    <@[T] [T], (usize, usize)> self {
+        slice(self.0, self.1 .0, self.1 .1)
+    } reversed(self.1 .0, self.1 .1)
 
-impl<'a, T: ?Sized> Clone for SliceRef<'a, T> {
-    fn clone(&self) -> Self {
-        *self
-    }
-}
+    <@[T, const N: usize] [T; N], (usize, usize)> self {
+        slice(self.0, self.1 .0, self.1 .1)
+    } reversed(self.1 .0, self.1 .1)
 
-impl<'a, T: ?Sized> Copy for SliceRef<'a, T> {}
+    <str, (usize, usize)> self {
+        str_slice(self.0, self.1 .0, self.1 .1)
+    } reversed(self.1 .0, self.1 .1)
 
-impl<'a> SliceRef<'a, str> {
-    pub const fn is_empty(self) -> bool {
-        self.0.is_empty()
-    }
+    <@[T] [T], IntRange<u16>> self {
+        slice(self.0, self.1 .0.start as usize, self.1 .0.end as usize)
+    } reversed(self.1 .0.start, self.1 .0.end)
 
-    pub const fn len(self) -> usize {
-        self.0.len()
+    <@[T, const N: usize] [T; N], IntRange<u16>> self {
+        slice(self.0, self.1 .0.start as usize, self.1 .0.end as usize)
+    } reversed(self.1 .0.start, self.1 .0.end)
+
+    <str, IntRange<u16>> self {
+        str_slice(self.0, self.1 .0.start as usize, self.1 .0.end as usize)
+    } reversed(self.1 .0.start, self.1 .0.end)
+
+    <@[T] [T], IntRange<u32>> self {
+        slice(self.0, self.1 .0.start as usize, self.1 .0.end as usize)
+    } reversed(self.1 .0.start, self.1 .0.end)
+
+    <@[T, const N: usize] [T; N], IntRange<u32>> self {
+        slice(self.0, self.1 .0.start as usize, self.1 .0.end as usize)
+    } reversed(self.1 .0.start, self.1 .0.end)
+
+    <str, IntRange<u32>> self {
+        str_slice(self.0, self.1 .0.start as usize, self.1 .0.end as usize)
+    } reversed(self.1 .0.start, self.1 .0.end)
+
+    <@[T] [T], IntRange<i32>> self {
+        slice_signed(self.0, self.1 .0.start, self.1 .0.end)
+    } reversed(self.1 .0.start, self.1 .0.end)
+
+    <@[T, const N: usize] [T; N], IntRange<i32>> self {
+        slice_signed(self.0, self.1 .0.start, self.1 .0.end)
+    } reversed(self.1 .0.start, self.1 .0.end)
+
+    <str, IntRange<i32>> self {
+        str_slice_signed(self.0, self.1 .0.start, self.1 .0.end)
+    } reversed(self.1 .0.start, self.1 .0.end)
+
+    <@[T] [T], Last> self {
+        slice_last(self.0, self.1 .0)
     }
 
-    pub const fn cmp(self, other: SliceRef<str>) -> Ordering {
-        SliceRef(self.0.as_bytes()).cmp(SliceRef(other.0.as_bytes()))
+    <@[T, const N: usize] [T; N], Last> self {
+        slice_last(self.0, self.1 .0)
     }
 
-    pub const fn partial_cmp(self, other: SliceRef<str>) -> Option<Ordering> {
-        SliceRef(self.0.as_bytes()).partial_cmp(SliceRef(other.0.as_bytes()))
+    <str, Last> self {
+        str_slice_last(self.0, self.1 .0)
     }
+
+    <@[T] [T], FromEnd> self {
+        slice_from_end(self.0, self.1 .0.start, self.1 .0.end)
+    } reversed(self.1 .0.start, self.1 .0.end)
+
+    <@[T, const N: usize] [T; N], FromEnd> self {
+        slice_from_end(self.0, self.1 .0.start, self.1 .0.end)
+    } reversed(self.1 .0.start, self.1 .0.end)
+
+    <str, FromEnd> self {
+        str_slice_from_end(self.0, self.1 .0.start, self.1 .0.end)
+    } reversed(self.1 .0.start, self.1 .0.end)
+
+    <str, Chars> self {
+        str_slice_chars(self.0, self.1 .0.start, self.1 .0.end)
+    } reversed(self.1 .0.start, self.1 .0.end)
 }
 
-macro_rules! impl_slice_cmp {
-    ($($t:ty),* $(,)?) => { $(
-        impl<'a> SliceRef<'a, [$t]> {
-            pub const fn is_empty(self) -> bool {
-                self.0.is_empty()
-            }
+macro_rules! impl_slice_ref_index {
+    ($($index:ty => |$r:pat_param| $owned:expr;)*) => { $(
+        impl<'r> Sealed for &'r $index {}
 
-            pub const fn len(self) -> usize {
-                self.0.len()
+        impl<'r> SliceIndex<str> for &'r $index {
+            type Output = str;
+        }
+
+        impl<'r, T> SliceIndex<[T]> for &'r $index {
+            type Output = [T];
+        }
+
+        impl<'r, T, const N: usize> SliceIndex<[T; N]> for &'r $index {
+            type Output = [T];
+        }
+
+        impl<'a, 'r, T> Slice<'a, [T], &'r $index> {
+            /// Evaluate this slice operation, or return the reason for the error
+            pub const fn checked_get(&self) -> Result<&'a [T], SliceError> {
+                let $r = self.1;
+                Slice(self.0, $owned).checked_get()
             }
 
-            pub const fn cmp(self, other: SliceRef<[$t]>) -> Ordering {
-                let len = self.0.len();
-                if len < other.0.len() {
-                    return Ordering::Less;
-                } else if len > other.0.len() {
-                    return Ordering::Greater;
-                }
-                let mut i = 0;
-                while i < len {
-                    if self.0[i] < other.0[i] {
-                        return Ordering::Less;
-                    } else if self.0[i] > other.0[i] {
-                        return Ordering::Greater;
-                    }
-                    i += 1
-                }
-                Ordering::Equal
+            /// Evaluate this slice operation, or return `None` on error
+            pub const fn get(&self) -> Option<&'a [T]> {
+                let $r = self.1;
+                Slice(self.0, $owned).get()
             }
 
-            pub const fn partial_cmp(self, other: SliceRef<[$t]>) -> Option<Ordering> {
-                Some(self.cmp(other))
+            /// Evaluate this slice operation, or panic on error
+            #[track_caller]
+            pub const fn index(&self) -> &'a [T] {
+                let $r = self.1;
+                Slice(self.0, $owned).index()
             }
         }
 
-        impl<'a, const N: usize> SliceRef<'a, [$t; N]> {
-            pub const fn is_empty(self) -> bool {
-                N != 0
+        impl<'a, 'r, T, const N: usize> Slice<'a, [T; N], &'r $index> {
+            /// Evaluate this slice operation, or return the reason for the error
+            pub const fn checked_get(&self) -> Result<&'a [T], SliceError> {
+                let $r = self.1;
+                Slice(self.0, $owned).checked_get()
             }
 
-            pub const fn len(self) -> usize {
-                N
+            /// Evaluate this slice operation, or return `None` on error
+            pub const fn get(&self) -> Option<&'a [T]> {
+                let $r = self.1;
+                Slice(self.0, $owned).get()
             }
 
-            pub const fn cmp<const M: usize>(self, other: SliceRef<[$t; M]>) -> Ordering {
-                SliceRef::<[$t]>(self.0).cmp(SliceRef::<[$t]>(other.0))
+            /// Evaluate this slice operation, or panic on error
+            #[track_caller]
+            pub const fn index(&self) -> &'a [T] {
+                let $r = self.1;
+                Slice(self.0, $owned).index()
             }
+        }
 
-            pub const fn partial_cmp<const M: usize>(self, other: SliceRef<[$t; M]>) -> Option<Ordering> {
-                Some(self.cmp(other))
+        impl<'a, 'r> Slice<'a, str, &'r $index> {
+            /// Evaluate this slice operation, or return the reason for the error
+            pub const fn checked_get(&self) -> Result<&'a str, SliceError> {
+                let $r = self.1;
+                Slice(self.0, $owned).checked_get()
             }
-        }
-    )* };
+
+            /// Evaluate this slice operation, or return `None` on error
+            pub const fn get(&self) -> Option<&'a str> {
+                let $r = self.1;
+                Slice(self.0, $owned).get()
+            }
+
+            /// Evaluate this slice operation, or panic on error
+            #[track_caller]
+            pub const fn index(&self) -> &'a str {
+                let $r = self.1;
+                Slice(self.0, $owned).index()
+            }
+        }
+    )* };
+}
+
+impl_slice_ref_index! {
+    Range<usize> => |r| r.start..r.end;
+    RangeInclusive<usize> => |r| RangeInclusive::new(*r.start(), *r.end());
+    RangeFrom<usize> => |r| r.start..;
+    RangeFull => |_| ..;
+    RangeTo<usize> => |r| ..r.end;
+    RangeToInclusive<usize> => |r| ..=r.end;
+}
+
+macro_rules! impl_slice_option_index {
+    ($($index:ty),* $(,)?) => { $(
+        impl Sealed for Option<$index> {}
+
+        impl SliceIndex<str> for Option<$index> {
+            type Output = str;
+        }
+
+        impl<T> SliceIndex<[T]> for Option<$index> {
+            type Output = [T];
+        }
+
+        impl<T, const N: usize> SliceIndex<[T; N]> for Option<$index> {
+            type Output = [T];
+        }
+
+        impl<'a, T> Slice<'a, [T], Option<$index>> {
+            /// Evaluate this slice operation, or return `None` if there's no index or on error
+            pub const fn get(&self) -> Option<&'a [T]> {
+                match &self.1 {
+                    Some(index) => Slice(self.0, index).get(),
+                    None => None,
+                }
+            }
+        }
+
+        impl<'a, T, const N: usize> Slice<'a, [T; N], Option<$index>> {
+            /// Evaluate this slice operation, or return `None` if there's no index or on error
+            pub const fn get(&self) -> Option<&'a [T]> {
+                match &self.1 {
+                    Some(index) => Slice(self.0, index).get(),
+                    None => None,
+                }
+            }
+        }
+
+        impl<'a> Slice<'a, str, Option<$index>> {
+            /// Evaluate this slice operation, or return `None` if there's no index or on error
+            pub const fn get(&self) -> Option<&'a str> {
+                match &self.1 {
+                    Some(index) => Slice(self.0, index).get(),
+                    None => None,
+                }
+            }
+        }
+    )* };
+}
+
+impl_slice_option_index!(
+    Range<usize>,
+    RangeInclusive<usize>,
+    RangeFrom<usize>,
+    RangeFull,
+    RangeTo<usize>,
+    RangeToInclusive<usize>,
+);
+
+macro_rules! impl_slice_clamped {
+    ($($index:ty => |$self:ident| $bounds:expr;)*) => { $(
+        impl<'a, T> Slice<'a, [T], $index> {
+            /// Evaluate this slice operation, clamping the range to the slice
+            pub const fn clamped(&$self) -> &'a [T] {
+                let (start, end) = $bounds;
+                slice_clamped($self.0, start, end)
+            }
+        }
+
+        impl<'a, T, const N: usize> Slice<'a, [T; N], $index> {
+            /// Evaluate this slice operation, clamping the range to the slice
+            pub const fn clamped(&$self) -> &'a [T] {
+                let (start, end) = $bounds;
+                slice_clamped($self.0, start, end)
+            }
+        }
+
+        impl<'a> Slice<'a, str, $index> {
+            /// Evaluate this slice operation, clamping the range inwards to the slice
+            /// and to codepoint boundaries
+            pub const fn clamped(&$self) -> &'a str {
+                let (start, end) = $bounds;
+                str_slice_clamped($self.0, start, end)
+            }
+        }
+    )* };
+}
+
+impl_slice_clamped! {
+    Range<usize> => |self| (self.1.start, self.1.end);
+    RangeInclusive<usize> => |self| (*self.1.start(), self.1.end().saturating_add(1));
+    RangeFrom<usize> => |self| (self.1.start, usize::MAX);
+    RangeFull => |self| (0, usize::MAX);
+    RangeTo<usize> => |self| (0, self.1.end);
+    RangeToInclusive<usize> => |self| (0, self.1.end.saturating_add(1));
+}
+
+const unsafe fn slice_unchecked<T>(s: &[T], start: usize, end: usize) -> &[T] {
+    debug_assert!(start <= end && end <= s.len(), "slice index out of range");
+    unsafe {
+        // safety: the caller guarantees that the range is valid
+        core::slice::from_raw_parts(s.as_ptr().add(start), end - start)
+    }
+}
+
+const unsafe fn str_slice_unchecked(s: &str, start: usize, end: usize) -> &str {
+    unsafe {
+        // safety: the caller guarantees that the range is valid and on codepoint boundaries
+        str::from_utf8_unchecked(slice_unchecked(s.as_bytes(), start, end))
+    }
+}
+
+macro_rules! impl_slice_unchecked {
+    ($($index:ty => |$self:ident, $len:pat_param| $bounds:expr;)*) => { $(
+        impl<'a, T> Slice<'a, [T], $index> {
+            /// Evaluate this slice operation without checking the range.
+            ///
+            /// # Safety
+            /// The range must be in bounds for the slice.
+            pub const unsafe fn index_unchecked(&$self) -> &'a [T] {
+                let $len = $self.0.len();
+                let (start, end) = $bounds;
+                unsafe { slice_unchecked($self.0, start, end) }
+            }
+        }
+
+        impl<'a, T, const N: usize> Slice<'a, [T; N], $index> {
+            /// Evaluate this slice operation without checking the range.
+            ///
+            /// # Safety
+            /// The range must be in bounds for the slice.
+            pub const unsafe fn index_unchecked(&$self) -> &'a [T] {
+                let $len = N;
+                let (start, end) = $bounds;
+                unsafe { slice_unchecked($self.0, start, end) }
+            }
+        }
+
+        impl<'a> Slice<'a, str, $index> {
+            /// Evaluate this slice operation without checking the range.
+            ///
+            /// # Safety
+            /// The range must be in bounds for the slice, and its start and end must be
+            /// on codepoint boundaries.
+            pub const unsafe fn index_unchecked(&$self) -> &'a str {
+                let $len = $self.0.len();
+                let (start, end) = $bounds;
+                unsafe { str_slice_unchecked($self.0, start, end) }
+            }
+        }
+    )* };
+}
+
+impl_slice_unchecked! {
+    Range<usize> => |self, _| (self.1.start, self.1.end);
+    RangeInclusive<usize> => |self, _| (*self.1.start(), *self.1.end() + 1);
+    RangeFrom<usize> => |self, len| (self.1.start, len);
+    RangeFull => |self, len| (0, len);
+    RangeTo<usize> => |self, _| (0, self.1.end);
+    RangeToInclusive<usize> => |self, _| (0, self.1.end + 1);
+}
+
+/// An argument to a comparison macro, which can be a reference or an array by value.
+/// [`get`](SliceArg::get) returns a reference in both cases.
+pub struct SliceArg<T>(pub T);
+
+impl<'a, T: ?Sized> SliceArg<&'a T> {
+    pub const fn get(&self) -> &'a T {
+        self.0
+    }
+}
+
+impl<T, const N: usize> SliceArg<[T; N]> {
+    pub const fn get(&self) -> &[T; N] {
+        &self.0
+    }
+}
+
+/// A reference to a slice or `str` for use in comparisons.
+///
+/// Only the runtime `PartialEq` and `Ord` trait implementations return early without
+/// comparing elements if both sides refer to the same slice. Pointers can't be compared in a
+/// const context, so the const `eq`, `cmp` and `partial_cmp` methods, and the macros built on
+/// them like [`slice_eq!`](crate::slice_eq) and [`slice_cmp!`](crate::slice_cmp), always
+/// compare the elements. The const `eq` methods and `PartialEq` return `false` without reading
+/// any elements if the lengths differ.
+///
+/// ```rust
+/// # extern crate std;
+/// # use const_it::SliceRef;
+/// let mut words = std::vec![SliceRef("const"), SliceRef("slice"), SliceRef("it")];
+/// words.sort();
+/// assert!(words == [SliceRef("const"), SliceRef("it"), SliceRef("slice")]);
+/// ```
+pub struct SliceRef<'a, T: ?Sized>(pub &'a T);
+
+impl<'a, T: ?Sized> Clone for SliceRef<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T: ?Sized> Copy for SliceRef<'a, T> {}
+
+impl<'a, T> SliceRef<'a, [T]> {
+    pub const fn as_slice(self) -> SliceRef<'a, [T]> {
+        self
+    }
+}
+
+impl<'a, T, const N: usize> SliceRef<'a, [T; N]> {
+    pub const fn as_slice(self) -> SliceRef<'a, [T]> {
+        SliceRef(self.0)
+    }
+}
+
+impl<'a> SliceRef<'a, str> {
+    pub const fn as_slice(self) -> SliceRef<'a, str> {
+        self
+    }
+
+    pub const fn is_palindrome(self) -> bool {
+        SliceRef(self.0.as_bytes()).is_palindrome()
+    }
+
+    pub const fn is_repetition_of(self, pattern: SliceRef<str>) -> bool {
+        SliceRef(self.0.as_bytes()).is_repetition_of(SliceRef(pattern.0.as_bytes()))
+    }
+
+    pub const fn is_empty(self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub const fn len(self) -> usize {
+        self.0.len()
+    }
+
+    pub const fn cmp(self, other: SliceRef<str>) -> Ordering {
+        ops::cmp_str(self.0, other.0)
+    }
+
+    pub const fn partial_cmp(self, other: SliceRef<str>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+
+    pub const fn eq(self, other: SliceRef<str>) -> bool {
+        ops::eq_str(self.0, other.0)
+    }
+
+    pub const fn strip_prefix(self, prefix: SliceRef<str>) -> Option<&'a str> {
+        ops::strip_prefix_str(self.0, prefix.0)
+    }
+
+    pub const fn strip_suffix(self, suffix: SliceRef<str>) -> Option<&'a str> {
+        ops::strip_suffix_str(self.0, suffix.0)
+    }
+
+    pub const fn first_diff(self, other: SliceRef<str>) -> Option<(usize, Ordering)> {
+        SliceRef(self.0.as_bytes()).first_diff(SliceRef(other.0.as_bytes()))
+    }
+
+    pub const fn shortlex_cmp(self, other: SliceRef<str>) -> Ordering {
+        SliceRef(self.0.as_bytes()).shortlex_cmp(SliceRef(other.0.as_bytes()))
+    }
+}
+
+impl<'a> PartialEq for SliceRef<'a, str> {
+    fn eq(&self, other: &Self) -> bool {
+        // fast path for comparing a slice with itself
+        ptr::eq(self.0, other.0) || (*self).eq(*other)
+    }
+}
+
+impl<'a> Eq for SliceRef<'a, str> {}
+
+impl<'a> PartialOrd for SliceRef<'a, str> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(Ord::cmp(self, other))
+    }
+}
+
+impl<'a> Ord for SliceRef<'a, str> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if ptr::eq(self.0, other.0) {
+            // fast path for comparing a slice with itself
+            Ordering::Equal
+        } else {
+            (*self).cmp(*other)
+        }
+    }
+}
+
+macro_rules! impl_slice_cmp {
+    ($($t:ty),* $(,)?) => { $(
+        impl<'a> SliceRef<'a, [$t]> {
+            pub const fn is_empty(self) -> bool {
+                self.0.is_empty()
+            }
+
+            pub const fn len(self) -> usize {
+                self.0.len()
+            }
+
+            pub const fn partial_cmp(self, other: SliceRef<[$t]>) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+
+            pub const fn first_diff(self, other: SliceRef<[$t]>) -> Option<(usize, Ordering)> {
+                let mut i = 0;
+                while i < self.0.len() && i < other.0.len() {
+                    if self.0[i] < other.0[i] {
+                        return Some((i, Ordering::Less));
+                    } else if self.0[i] > other.0[i] {
+                        return Some((i, Ordering::Greater));
+                    }
+                    i += 1;
+                }
+                if self.0.len() < other.0.len() {
+                    Some((i, Ordering::Less))
+                } else if self.0.len() > other.0.len() {
+                    Some((i, Ordering::Greater))
+                } else {
+                    None
+                }
+            }
+
+            pub const fn shortlex_cmp(self, other: SliceRef<[$t]>) -> Ordering {
+                if self.0.len() < other.0.len() {
+                    Ordering::Less
+                } else if self.0.len() > other.0.len() {
+                    Ordering::Greater
+                } else {
+                    self.cmp(other)
+                }
+            }
+
+            pub const fn is_palindrome(self) -> bool {
+                let len = self.0.len();
+                let mut i = 0;
+                while i < len / 2 {
+                    if self.0[i] != self.0[len - 1 - i] {
+                        return false;
+                    }
+                    i += 1;
+                }
+                true
+            }
+
+            pub const fn is_uniform(self) -> bool {
+                let mut i = 1;
+                while i < self.0.len() {
+                    if self.0[i] != self.0[0] {
+                        return false;
+                    }
+                    i += 1;
+                }
+                true
+            }
+
+            pub const fn is_repetition_of(self, pattern: SliceRef<[$t]>) -> bool {
+                if pattern.0.is_empty() {
+                    return self.0.is_empty();
+                }
+                if self.0.len() % pattern.0.len() != 0 {
+                    return false;
+                }
+                let mut i = 0;
+                while i < self.0.len() {
+                    if self.0[i] != pattern.0[i % pattern.0.len()] {
+                        return false;
+                    }
+                    i += 1;
+                }
+                true
+            }
+
+            pub const fn take_while_eq(self, value: $t) -> &'a [$t] {
+                self.0.split_at(self.count_leading(value)).0
+            }
+
+            pub const fn trim_start_matches(self, value: $t) -> &'a [$t] {
+                self.0.split_at(self.count_leading(value)).1
+            }
+
+            pub const fn trim_end_matches(self, value: $t) -> &'a [$t] {
+                self.0.split_at(self.0.len() - self.count_trailing(value)).0
+            }
+
+            pub const fn min_index(self) -> Option<usize> {
+                if self.0.is_empty() {
+                    return None;
+                }
+                let mut best = 0;
+                let mut i = 1;
+                while i < self.0.len() {
+                    if self.0[i] < self.0[best] {
+                        best = i;
+                    }
+                    i += 1;
+                }
+                Some(best)
+            }
+
+            pub const fn max_index(self) -> Option<usize> {
+                if self.0.is_empty() {
+                    return None;
+                }
+                let mut best = 0;
+                let mut i = 1;
+                while i < self.0.len() {
+                    if self.0[i] > self.0[best] {
+                        best = i;
+                    }
+                    i += 1;
+                }
+                Some(best)
+            }
+
+            pub const fn min(self) -> Option<&'a $t> {
+                match self.min_index() {
+                    Some(i) => Some(&self.0[i]),
+                    None => None,
+                }
+            }
+
+            pub const fn max(self) -> Option<&'a $t> {
+                match self.max_index() {
+                    Some(i) => Some(&self.0[i]),
+                    None => None,
+                }
+            }
+
+            pub const fn min_max(self) -> Option<(&'a $t, &'a $t)> {
+                if self.0.is_empty() {
+                    return None;
+                }
+                let (mut min, mut max) = (0, 0);
+                let mut i = 1;
+                while i < self.0.len() {
+                    if self.0[i] < self.0[min] {
+                        min = i;
+                    } else if self.0[i] > self.0[max] {
+                        max = i;
+                    }
+                    i += 1;
+                }
+                Some((&self.0[min], &self.0[max]))
+            }
+
+            pub const fn partition_counts(self, pivot: $t) -> (usize, usize, usize) {
+                let (mut less, mut equal, mut greater) = (0, 0, 0);
+                let mut i = 0;
+                while i < self.0.len() {
+                    if self.0[i] < pivot {
+                        less += 1;
+                    } else if self.0[i] > pivot {
+                        greater += 1;
+                    } else {
+                        equal += 1;
+                    }
+                    i += 1;
+                }
+                (less, equal, greater)
+            }
+
+            pub const fn position(self, value: $t) -> Option<usize> {
+                let mut i = 0;
+                while i < self.0.len() {
+                    if self.0[i] == value {
+                        return Some(i);
+                    }
+                    i += 1;
+                }
+                None
+            }
+
+            pub const fn rposition(self, value: $t) -> Option<usize> {
+                let mut i = self.0.len();
+                while i > 0 {
+                    i -= 1;
+                    if self.0[i] == value {
+                        return Some(i);
+                    }
+                }
+                None
+            }
+
+            pub const fn split_once(self, separator: $t) -> Option<(&'a [$t], &'a [$t])> {
+                let i = unwrap_some_or_return!(self.position(separator));
+                let (head, tail) = self.0.split_at(i);
+                Some((head, tail.split_at(1).1))
+            }
+
+            pub const fn split_inclusive_once(
+                self,
+                separator: $t,
+            ) -> Option<(&'a [$t], &'a [$t])> {
+                let i = unwrap_some_or_return!(self.position(separator));
+                Some(self.0.split_at(i + 1))
+            }
+
+            pub const fn rsplit_once(self, separator: $t) -> Option<(&'a [$t], &'a [$t])> {
+                let i = unwrap_some_or_return!(self.rposition(separator));
+                let (head, tail) = self.0.split_at(i);
+                Some((head, tail.split_at(1).1))
+            }
+
+            pub const fn count_leading(self, value: $t) -> usize {
+                let mut i = 0;
+                while i < self.0.len() && self.0[i] == value {
+                    i += 1;
+                }
+                i
+            }
+
+            pub const fn count_trailing(self, value: $t) -> usize {
+                let mut i = 0;
+                while i < self.0.len() && self.0[self.0.len() - 1 - i] == value {
+                    i += 1;
+                }
+                i
+            }
+
+            pub const fn run_boundaries<const N: usize>(self) -> ([usize; N], usize) {
+                assert!(
+                    self.0.len() == N,
+                    "output length must be the length of the slice"
+                );
+                let mut out = [0; N];
+                let mut count = 0;
+                let mut i = 0;
+                while i < N {
+                    if i == 0 || self.0[i] != self.0[i - 1] {
+                        out[count] = i;
+                        count += 1;
+                    }
+                    i += 1;
+                }
+                (out, count)
+            }
+
+            pub const fn to_unique_array<const N: usize>(
+                self,
+            ) -> Result<([$t; N], usize), &'static str> {
+                let mut out: [$t; N] = unsafe {
+                    // safety: all zero bytes is a valid value for all the primitive types here
+                    MaybeUninit::zeroed().assume_init()
+                };
+                let mut count = 0;
+                let mut i = 0;
+                while i < self.0.len() {
+                    let mut j = 0;
+                    while j < count && out[j] != self.0[i] {
+                        j += 1;
+                    }
+                    if j == count {
+                        if count == N {
+                            return Err("more unique values than the capacity of the array");
+                        }
+                        out[count] = self.0[i];
+                        count += 1;
+                    }
+                    i += 1;
+                }
+                Ok((out, count))
+            }
+
+            pub const fn longest_run(self, value: $t) -> (usize, usize) {
+                let (mut best_start, mut best_len) = (0, 0);
+                let mut start = 0;
+                let mut i = 0;
+                while i < self.0.len() {
+                    if self.0[i] != value {
+                        start = i + 1;
+                    } else if i + 1 - start > best_len {
+                        best_start = start;
+                        best_len = i + 1 - start;
+                    }
+                    i += 1;
+                }
+                (best_start, best_len)
+            }
+        }
+
+        impl<'a> PartialEq for SliceRef<'a, [$t]> {
+            fn eq(&self, other: &Self) -> bool {
+                // fast path for comparing a slice with itself
+                ptr::eq(self.0, other.0) || (*self).eq(*other)
+            }
+        }
+
+        impl<'a> Eq for SliceRef<'a, [$t]> {}
+
+        impl<'a> PartialOrd for SliceRef<'a, [$t]> {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(Ord::cmp(self, other))
+            }
+        }
+
+        impl<'a> Ord for SliceRef<'a, [$t]> {
+            fn cmp(&self, other: &Self) -> Ordering {
+                if ptr::eq(self.0, other.0) {
+                    // fast path for comparing a slice with itself
+                    Ordering::Equal
+                } else {
+                    (*self).cmp(*other)
+                }
+            }
+        }
+
+        impl<'a, const N: usize> SliceRef<'a, [$t; N]> {
+            pub const fn is_empty(self) -> bool {
+                N != 0
+            }
+
+            pub const fn len(self) -> usize {
+                N
+            }
+
+            pub const fn cmp<const M: usize>(self, other: SliceRef<[$t; M]>) -> Ordering {
+                SliceRef::<[$t]>(self.0).cmp(SliceRef::<[$t]>(other.0))
+            }
+
+            pub const fn partial_cmp<const M: usize>(self, other: SliceRef<[$t; M]>) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+
+            pub const fn eq<const M: usize>(self, other: SliceRef<[$t; M]>) -> bool {
+                SliceRef::<[$t]>(self.0).eq(SliceRef::<[$t]>(other.0))
+            }
+        }
+
+        impl<'a, const N: usize> SliceRef<'a, [[$t; N]]> {
+            pub const fn cmp(self, other: SliceRef<[[$t; N]]>) -> Ordering {
+                let mut i = 0;
+                while i < self.0.len() && i < other.0.len() {
+                    match SliceRef::<[$t]>(&self.0[i]).cmp(SliceRef::<[$t]>(&other.0[i])) {
+                        Ordering::Equal => i += 1,
+                        ordering => return ordering,
+                    }
+                }
+                if self.0.len() < other.0.len() {
+                    Ordering::Less
+                } else if self.0.len() > other.0.len() {
+                    Ordering::Greater
+                } else {
+                    Ordering::Equal
+                }
+            }
+
+            pub const fn partial_cmp(self, other: SliceRef<[[$t; N]]>) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+
+            pub const fn eq(self, other: SliceRef<[[$t; N]]>) -> bool {
+                if self.0.len() != other.0.len() {
+                    return false;
+                }
+                let mut i = 0;
+                while i < self.0.len() {
+                    if !SliceRef::<[$t]>(&self.0[i]).eq(SliceRef::<[$t]>(&other.0[i])) {
+                        return false;
+                    }
+                    i += 1;
+                }
+                true
+            }
+        }
+
+        impl<'a, const N: usize, const M: usize> SliceRef<'a, [[$t; N]; M]> {
+            pub const fn cmp<const K: usize>(self, other: SliceRef<[[$t; N]; K]>) -> Ordering {
+                SliceRef::<[[$t; N]]>(self.0).cmp(SliceRef::<[[$t; N]]>(other.0))
+            }
+
+            pub const fn partial_cmp<const K: usize>(
+                self,
+                other: SliceRef<[[$t; N]; K]>,
+            ) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+
+            pub const fn eq<const K: usize>(self, other: SliceRef<[[$t; N]; K]>) -> bool {
+                SliceRef::<[[$t; N]]>(self.0).eq(SliceRef::<[[$t; N]]>(other.0))
+            }
+        }
+
+        impl<'a, const N: usize> PartialEq for SliceRef<'a, [$t; N]> {
+            fn eq(&self, other: &Self) -> bool {
+                // fast path for comparing a slice with itself
+                ptr::eq(self.0, other.0) || (*self).eq(*other)
+            }
+        }
+
+        impl<'a, const N: usize> Eq for SliceRef<'a, [$t; N]> {}
+
+        impl<'a, const N: usize> PartialOrd for SliceRef<'a, [$t; N]> {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(Ord::cmp(self, other))
+            }
+        }
+
+        impl<'a, const N: usize> Ord for SliceRef<'a, [$t; N]> {
+            fn cmp(&self, other: &Self) -> Ordering {
+                if ptr::eq(self.0, other.0) {
+                    // fast path for comparing a slice with itself
+                    Ordering::Equal
+                } else {
+                    (*self).cmp(*other)
+                }
+            }
+        }
+    )* };
 }
 
 impl_slice_cmp!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, char, bool);
+
+macro_rules! impl_slice_eq {
+    ($($t:ty),* $(,)?) => { $(
+        impl<'a> SliceRef<'a, [$t]> {
+            pub const fn cmp(self, other: SliceRef<[$t]>) -> Ordering {
+                let len = if self.0.len() < other.0.len() {
+                    self.0.len()
+                } else {
+                    other.0.len()
+                };
+                let mut i = 0;
+                while i < len {
+                    if self.0[i] < other.0[i] {
+                        return Ordering::Less;
+                    } else if self.0[i] > other.0[i] {
+                        return Ordering::Greater;
+                    }
+                    i += 1
+                }
+                if self.0.len() < other.0.len() {
+                    Ordering::Less
+                } else if self.0.len() > other.0.len() {
+                    Ordering::Greater
+                } else {
+                    Ordering::Equal
+                }
+            }
+
+            pub const fn eq(self, other: SliceRef<[$t]>) -> bool {
+                if self.0.len() != other.0.len() {
+                    return false;
+                }
+                let mut i = 0;
+                while i < self.0.len() {
+                    if self.0[i] != other.0[i] {
+                        return false;
+                    }
+                    i += 1;
+                }
+                true
+            }
+
+            pub const fn strip_prefix(self, prefix: SliceRef<[$t]>) -> Option<&'a [$t]> {
+                if self.0.len() < prefix.0.len() {
+                    return None;
+                }
+                let (head, rest) = self.0.split_at(prefix.0.len());
+                if SliceRef(head).eq(prefix) {
+                    Some(rest)
+                } else {
+                    None
+                }
+            }
+
+            pub const fn strip_suffix(self, suffix: SliceRef<[$t]>) -> Option<&'a [$t]> {
+                if self.0.len() < suffix.0.len() {
+                    return None;
+                }
+                let (rest, tail) = self.0.split_at(self.0.len() - suffix.0.len());
+                if SliceRef(tail).eq(suffix) {
+                    Some(rest)
+                } else {
+                    None
+                }
+            }
+        }
+    )* };
+}
+
+// u8 slices use the implementations in `ops`
+impl_slice_eq!(i8, i16, i32, i64, i128, isize, u16, u32, u64, u128, usize, char, bool);
+
+impl<'a> SliceRef<'a, [u8]> {
+    pub const fn cmp(self, other: SliceRef<[u8]>) -> Ordering {
+        ops::cmp_bytes(self.0, other.0)
+    }
+
+    pub const fn eq(self, other: SliceRef<[u8]>) -> bool {
+        ops::eq_bytes(self.0, other.0)
+    }
+
+    pub const fn strip_prefix(self, prefix: SliceRef<[u8]>) -> Option<&'a [u8]> {
+        ops::strip_prefix_bytes(self.0, prefix.0)
+    }
+
+    pub const fn strip_suffix(self, suffix: SliceRef<[u8]>) -> Option<&'a [u8]> {
+        ops::strip_suffix_bytes(self.0, suffix.0)
+    }
+}
+
+macro_rules! impl_slice_eq_ignore_ascii_case {
+    ($($t:ty),* $(,)?) => { $(
+        impl<'a> SliceRef<'a, [$t]> {
+            pub const fn eq_ignore_ascii_case(self, other: SliceRef<[$t]>) -> bool {
+                if self.0.len() != other.0.len() {
+                    return false;
+                }
+                let mut i = 0;
+                while i < self.0.len() {
+                    if !self.0[i].eq_ignore_ascii_case(&other.0[i]) {
+                        return false;
+                    }
+                    i += 1;
+                }
+                true
+            }
+        }
+    )* };
+}
+
+impl_slice_eq_ignore_ascii_case!(u8, char);
+
+impl<'a> SliceRef<'a, str> {
+    pub const fn eq_ignore_ascii_case(self, other: SliceRef<str>) -> bool {
+        SliceRef(self.0.as_bytes()).eq_ignore_ascii_case(SliceRef(other.0.as_bytes()))
+    }
+}
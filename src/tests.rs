@@ -1,9 +1,16 @@
 #![allow(clippy::bool_assert_comparison)]
 
+extern crate alloc;
+extern crate std;
+
 use super::*;
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
 use core::{
     cmp::Ordering,
-    ops::{Range, RangeInclusive},
+    ops::{Range, RangeFrom, RangeInclusive},
 };
 
 macro_rules! cmp_slice {
@@ -57,6 +64,435 @@ fn byte_slice() {
     slice_fail!(&[u8], b"abcde", RangeInclusive::new(4, 3));
 }
 
+#[test]
+fn int_range() {
+    const START: u32 = 1;
+    const END: u32 = 3;
+    const U32: &[u8] = slice!(b"abcde", IntRange(START..END));
+    assert_eq!(U32, b"bc");
+    const U16: &str = slice!("abcde", IntRange(2u16..5));
+    assert_eq!(U16, "cde");
+    const I32: &[i32] = slice!(&[1, 2, 3], IntRange(0i32..1));
+    assert_eq!(I32, &[1]);
+
+    assert_eq!(try_slice!("✨", IntRange(0u32..1)), None);
+    assert_eq!(try_slice!(b"abc", IntRange(2u16..4)), None);
+    assert_eq!(try_slice!(b"abc", IntRange(-1i32..2)), None);
+    assert_eq!(
+        try_slice!(
+            b"abc",
+            IntRange(Range {
+                start: 2i32,
+                end: 1
+            })
+        ),
+        None
+    );
+    assert_eq!(try_slice!("abc", IntRange(-2i32..-1)), None);
+}
+
+#[test]
+fn tuple_index() {
+    const fn bounds() -> (usize, usize) {
+        (1, 3)
+    }
+    const STR: &str = slice!("abcde", bounds());
+    assert_eq!(STR, slice!("abcde", 1..3));
+    const BYTES: &[u8] = slice!(b"abcde", (1, 3));
+    assert_eq!(BYTES, slice!(b"abcde", 1..3));
+    const SLICE: &[i32] = slice!([1, 2, 3].as_slice(), (0, 3));
+    assert_eq!(SLICE, &[1, 2, 3]);
+
+    slice_fail!(&str, "abcde", (4, 3));
+    slice_fail!(&[u8], b"abcde", (4, 6));
+    slice_fail!(&str, "✨", (0, 1));
+}
+
+#[test]
+fn try_slice_option() {
+    const SOME: Option<&str> = try_slice!("abcde", Some(1..3));
+    assert_eq!(SOME, Some("bc"));
+    const NONE: Option<&str> = try_slice!("abcde", None::<Range<usize>>);
+    assert_eq!(NONE, None);
+    const OUT_OF_RANGE: Option<&[u8]> = try_slice!(b"abcde", Some(3..6));
+    assert_eq!(OUT_OF_RANGE, None);
+    const FROM: Option<&[u8]> = try_slice!(b"abcde", Some(3..));
+    assert_eq!(FROM, Some(&b"de"[..]));
+    const START: Option<usize> = str_find_char!("abcde", 'c');
+    const RANGE: Option<RangeFrom<usize>> = match START {
+        Some(start) => Some(start..),
+        None => None,
+    };
+    const REST: Option<&[u8]> = try_slice!(b"abcde", RANGE);
+    assert_eq!(REST, Some(&b"cde"[..]));
+}
+
+#[test]
+fn str_slice_chars() {
+    const STR: &str = slice!("✨💖ab", Chars(1..3));
+    assert_eq!(STR, "💖a");
+    const ALL: &str = slice!("✨💖ab", Chars(0..4));
+    assert_eq!(ALL, "✨💖ab");
+    const EMPTY: Option<&str> = try_slice!("✨💖ab", Chars(4..4));
+    assert_eq!(EMPTY, Some(""));
+    const OUT_OF_RANGE: Result<&str, SliceError> = slice_try_result!("✨💖ab", Chars(2..5));
+    assert_eq!(OUT_OF_RANGE, Err(SliceError::OutOfRange));
+    const REVERSED: Result<&str, SliceError> =
+        slice_try_result!("✨💖ab", Chars(Range { start: 2, end: 1 }));
+    assert_eq!(REVERSED, Err(SliceError::StartGreaterThanEnd));
+    let s = "aé✨💖";
+    for start in 0..=4 {
+        for end in start..=4 {
+            let expected: String = s.chars().skip(start).take(end - start).collect();
+            assert_eq!(slice!(s, Chars(start..end)), expected);
+        }
+    }
+}
+
+#[test]
+fn slice_new() {
+    const SRC: Slice<[u8], ()> = Slice::new(b"abcde".as_slice());
+    const HEAD: &[u8] = SRC.at(..2).index();
+    const TAIL: Option<&[u8]> = SRC.at(Last(2)).get();
+    const NONE: Option<&[u8]> = SRC.at(4..6).get();
+    assert_eq!(HEAD, b"ab");
+    assert_eq!(TAIL, Some(&b"de"[..]));
+    assert_eq!(NONE, None);
+
+    let src = Slice::new("✨ab");
+    assert_eq!(src.at(3..).index(), "ab");
+    assert_eq!(src.at(1..).get(), None);
+}
+
+#[test]
+fn slice_mut() {
+    fn halves<T>(items: &mut [T]) -> (&[T], &[T]) {
+        let mid = items.len() / 2;
+        (slice!(items, ..mid), slice!(items, mid..))
+    }
+
+    let mut array = [1i32, 2, 3, 4, 5];
+    let items: &mut [i32; 5] = &mut array;
+    assert_eq!(slice!(items, 1..3), [2, 3]);
+    assert_eq!(try_slice!(items, 4..6), None);
+    items[0] = 0;
+    let items: &mut [i32] = items;
+    assert_eq!(slice!(items, ..=1), [0, 2]);
+    assert_eq!(halves(items), (&[0, 2][..], &[3, 4, 5][..]));
+    items[4] = 6;
+    assert_eq!(array, [0, 2, 3, 4, 6]);
+}
+
+#[test]
+fn slice_try_result() {
+    use SliceError::*;
+
+    const OK: Result<&str, SliceError> = slice_try_result!("abcde", 1..=2);
+    assert_eq!(OK, Ok("bc"));
+    const ELEMENT: Result<&u8, SliceError> = slice_try_result!(b"abc", 1);
+    assert_eq!(ELEMENT, Ok(&b'b'));
+
+    assert_eq!(
+        slice_try_result!(b"abc", Range { start: 2, end: 1 }),
+        Err(StartGreaterThanEnd)
+    );
+    assert_eq!(
+        slice_try_result!("abc", RangeInclusive::new(2, 0)),
+        Err(StartGreaterThanEnd)
+    );
+    assert_eq!(
+        slice_try_result!(b"abc".as_slice(), FromEnd(Range { start: 2, end: 1 })),
+        Err(StartGreaterThanEnd)
+    );
+    assert_eq!(slice_try_result!("abc", (2, 1)), Err(StartGreaterThanEnd));
+
+    assert_eq!(slice_try_result!(b"abc", 3), Err(OutOfRange));
+    assert_eq!(slice_try_result!(b"abc".as_slice(), ..4), Err(OutOfRange));
+    assert_eq!(slice_try_result!("abc", 4..), Err(OutOfRange));
+    assert_eq!(slice_try_result!("abc", ..=usize::MAX), Err(OutOfRange));
+    assert_eq!(slice_try_result!(b"abc", Last(4)), Err(OutOfRange));
+    assert_eq!(
+        slice_try_result!(b"abc", IntRange(-1i32..1)),
+        Err(OutOfRange)
+    );
+
+    assert_eq!(slice_try_result!("✨", 1..), Err(SplitsCodepoint));
+    assert_eq!(slice_try_result!("✨", ..=0), Err(SplitsCodepoint));
+    assert_eq!(slice_try_result!("a✨", Last(2)), Err(SplitsCodepoint));
+    assert_eq!(slice_try_result!("a✨", &(0..2)), Err(SplitsCodepoint));
+}
+
+#[test]
+fn try_slice_usize_out_of_range() {
+    const NONE: Option<&u8> = try_slice!(b"abc", 3);
+    assert_eq!(NONE, None);
+    assert_eq!(try_slice!([1i32, 2].as_slice(), 2), None);
+}
+
+#[test]
+#[should_panic(expected = "slice index out of range")]
+fn range_from_out_of_range_panic() {
+    let start = 4;
+    let _ = slice!(b"abc", start..);
+}
+
+#[test]
+fn slice_reversed_message() {
+    const MESSAGE: &str = error::start_greater_than_end_message(4, 3).as_str();
+    assert_eq!(MESSAGE, "slice index start 4 is higher than end 3");
+    assert_eq!(
+        error::start_greater_than_end_message(-1, -3).as_str(),
+        "slice index start -1 is higher than end -3"
+    );
+    assert_eq!(
+        error::start_greater_than_end_message(i128::MIN, 0).as_str(),
+        "slice index start -170141183460469231731687303715884105728 is higher than end 0"
+    );
+    assert_eq!(
+        error::start_greater_than_end_message(0, i128::MIN).as_str(),
+        "slice index start 0 is higher than end -170141183460469231731687303715884105728"
+    );
+}
+
+#[test]
+#[should_panic(expected = "slice index start 4 is higher than end 3")]
+fn slice_reversed_panic() {
+    let (start, end) = (4, 3);
+    let _ = slice!("abcde", Range { start, end });
+}
+
+#[test]
+#[should_panic(expected = "slice index start -1 is higher than end -3")]
+fn slice_reversed_int_range_panic() {
+    let _ = slice!(
+        "abcde",
+        IntRange(Range {
+            start: -1i32,
+            end: -3
+        })
+    );
+}
+
+#[test]
+fn inclusive_overflow() {
+    slice_fail!(&[u8], b"abcde", RangeInclusive::new(0, usize::MAX));
+    slice_fail!(
+        &[u8],
+        b"abcde".as_slice(),
+        RangeInclusive::new(0, usize::MAX)
+    );
+    slice_fail!(&[u8], b"abcde", RangeInclusive::new(usize::MAX, usize::MAX));
+    slice_fail!(&[u8], b"abcde", ..=usize::MAX);
+    slice_fail!(&str, "abcde", RangeInclusive::new(0, usize::MAX));
+    slice_fail!(&str, "abcde", ..=usize::MAX);
+}
+
+#[test]
+fn str_inclusive_overflow() {
+    slice_fail!(&str, "", ..=usize::MAX);
+    slice_fail!(&str, "", RangeInclusive::new(0, usize::MAX));
+    slice_fail!(&str, "abcde", RangeInclusive::new(usize::MAX, usize::MAX));
+    slice_fail!(&str, "abcde", &(..=usize::MAX));
+    slice_fail!(&str, "✨", RangeInclusive::new(1, usize::MAX));
+
+    const CLAMPED: &str = slice_clamped!("a✨", ..=usize::MAX);
+    assert_eq!(CLAMPED, "a✨");
+}
+
+#[test]
+#[should_panic(expected = "slice index out of range")]
+fn str_inclusive_overflow_panic() {
+    let _ = slice!("✨", RangeInclusive::new(1, usize::MAX));
+}
+
+#[test]
+#[should_panic(expected = "slice index out of range")]
+fn inclusive_overflow_panic() {
+    let _ = slice!(b"abcde".as_slice(), RangeInclusive::new(0, usize::MAX));
+}
+
+#[test]
+fn index_unchecked() {
+    const BYTES: &[u8] = unsafe { slice_index_unchecked!(b"abcde", 1..3) };
+    assert_eq!(BYTES, b"bc");
+
+    const BYTES_INCLUSIVE: &[u8] = unsafe { slice_index_unchecked!(b"abcde".as_slice(), 1..=3) };
+    assert_eq!(BYTES_INCLUSIVE, b"bcd");
+
+    const BYTES_FROM: &[u8] = unsafe { slice_index_unchecked!(b"abcde", 5..) };
+    assert_eq!(BYTES_FROM, b"");
+
+    const STR: &str = unsafe { slice_index_unchecked!("a✨💖b", 1..=7) };
+    assert_eq!(STR, "✨💖");
+
+    const STR_TO: &str = unsafe { slice_index_unchecked!("a✨💖b", ..4) };
+    assert_eq!(STR_TO, "a✨");
+
+    const STR_FULL: &str = unsafe { slice_index_unchecked!("a✨💖b", ..) };
+    assert_eq!(STR_FULL, "a✨💖b");
+
+    let s = "const slice";
+    for start in 0..=s.len() {
+        for end in start..=s.len() {
+            assert_eq!(
+                unsafe { slice_index_unchecked!(s, start..end) },
+                &s[start..end]
+            );
+        }
+    }
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "slice index out of range")]
+fn index_unchecked_debug_assert() {
+    let _ = unsafe { slice_index_unchecked!(b"abcde".as_slice(), 3..6) };
+}
+
+#[test]
+fn clamped() {
+    const BYTES: &[u8] = slice_clamped!(b"abcde", 1..3);
+    assert_eq!(BYTES, b"bc");
+
+    const BYTES_LONG: &[u8] = slice_clamped!(b"abcde", 2..10);
+    assert_eq!(BYTES_LONG, b"cde");
+
+    const BYTES_INCLUSIVE: &[u8] = slice_clamped!(b"abcde".as_slice(), ..=usize::MAX);
+    assert_eq!(BYTES_INCLUSIVE, b"abcde");
+
+    const BYTES_REVERSED: &[u8] = slice_clamped!(b"abcde", Range { start: 4, end: 2 });
+    assert_eq!(BYTES_REVERSED, b"");
+
+    const BYTES_PAST_END: &[u8] = slice_clamped!(b"abcde", 7..);
+    assert_eq!(BYTES_PAST_END, b"");
+
+    const STR_LONG: &str = slice_clamped!("abcde", ..10);
+    assert_eq!(STR_LONG, "abcde");
+
+    const STR_REVERSED: &str = slice_clamped!("abcde", RangeInclusive::new(4, 2));
+    assert_eq!(STR_REVERSED, "");
+
+    const STR_CODEPOINT: &str = slice_clamped!("a✨💖b", 2..6);
+    assert_eq!(STR_CODEPOINT, "");
+
+    const STR_CODEPOINT_2: &str = slice_clamped!("a✨💖b", 1..6);
+    assert_eq!(STR_CODEPOINT_2, "✨");
+
+    const STR_CODEPOINT_3: &str = slice_clamped!("a✨💖b", 2..);
+    assert_eq!(STR_CODEPOINT_3, "💖b");
+
+    const STR_FULL: &str = slice_clamped!("a✨💖b", ..);
+    assert_eq!(STR_FULL, "a✨💖b");
+}
+
+#[test]
+fn slice_prefix() {
+    const BYTES: &[u8] = slice_prefix!(b"01234", 3);
+    assert_eq!(BYTES, b"012");
+    const LONG: &[u8] = slice_prefix!(b"01234", 10);
+    assert_eq!(LONG, b"01234");
+    const STR: &str = slice_prefix!("const", 100);
+    assert_eq!(STR, "const");
+    const CODEPOINT: &str = slice_prefix!("a✨b", 3);
+    assert_eq!(CODEPOINT, "a");
+    const ARRAY: &[i32] = slice_prefix!(&[1i32, 2, 3], usize::MAX);
+    assert_eq!(ARRAY, [1, 2, 3]);
+}
+
+#[test]
+fn range_ref() {
+    const RANGE: Range<usize> = 1..3;
+    const BYTES: &[u8] = slice!(b"abcde", &RANGE);
+    const BYTES_2: &[u8] = slice!(b"abcde".as_slice(), &RANGE);
+    const STR: &str = slice!("abcde", &RANGE);
+    assert_eq!(BYTES, b"bc");
+    assert_eq!(BYTES_2, b"bc");
+    assert_eq!(STR, "bc");
+
+    const INCLUSIVE: RangeInclusive<usize> = 1..=3;
+    const STR_INCLUSIVE: Option<&str> = try_slice!("abcde", &INCLUSIVE);
+    assert_eq!(STR_INCLUSIVE, Some("bcd"));
+
+    const TRY_FAIL: Option<&str> = try_slice!("✨", &RANGE);
+    assert_eq!(TRY_FAIL, None);
+
+    const FROM: &[u8] = slice!(b"abcde", &(3..));
+    assert_eq!(FROM, b"de");
+
+    const FULL: &str = slice!("abcde", &..);
+    assert_eq!(FULL, "abcde");
+
+    const TO: &str = slice!("abcde", &..2);
+    assert_eq!(TO, "ab");
+
+    const TO_INCLUSIVE: &[u8] = slice!(b"abcde", &..=2);
+    assert_eq!(TO_INCLUSIVE, b"abc");
+
+    let range = 2..4;
+    assert_eq!(slice!("abcde", &range), "cd");
+    assert_eq!(slice!(b"abcde", &range), b"cd");
+    assert_eq!(range, 2..4);
+}
+
+#[test]
+fn last() {
+    const BYTES: &[u8] = slice!(b"abcde", Last(2));
+    assert_eq!(BYTES, b"de");
+
+    const ALL_BYTES: &[u8] = slice!(b"abcde".as_slice(), Last(5));
+    assert_eq!(ALL_BYTES, b"abcde");
+
+    const NO_BYTES: &[u8] = slice!(b"abcde", Last(0));
+    assert_eq!(NO_BYTES, b"");
+
+    const STR: &str = slice!("a✨💖", Last(4));
+    assert_eq!(STR, "💖");
+
+    const TRY_BYTES: Option<&[u8]> = try_slice!(b"abcde", Last(6));
+    assert_eq!(TRY_BYTES, None);
+
+    const TRY_STR: Option<&str> = try_slice!("a✨💖", Last(5));
+    assert_eq!(TRY_STR, None);
+
+    const TRY_STR_2: Option<&str> = try_slice!("a✨💖", Last(9));
+    assert_eq!(TRY_STR_2, None);
+}
+
+#[test]
+fn slice_last_chunk_array() {
+    const PACKET: &[u8] = b"\x01\x02payload\xde\xad\xbe\xef";
+    const CHECKSUM: Option<&[u8; 4]> = slice_last_chunk_array!(PACKET, 4);
+    assert_eq!(CHECKSUM, Some(&[0xde, 0xad, 0xbe, 0xef]));
+    const SHORT: Option<&[u8; 4]> = slice_last_chunk_array!(b"abc", 4);
+    assert_eq!(SHORT, None);
+    const EXACT: Option<&[i32; 2]> = slice_last_chunk_array!(&[1i32, 2], 2);
+    assert_eq!(EXACT, Some(&[1, 2]));
+    const EMPTY: Option<&[char; 0]> = slice_last_chunk_array!(&[] as &[char], 0);
+    assert_eq!(EMPTY, Some(&[]));
+}
+
+#[test]
+fn from_end() {
+    const BYTES: &[u8] = slice!(b"abcde", FromEnd(0..2));
+    assert_eq!(BYTES, slice!(b"abcde", 3..5));
+
+    const BYTES_2: &[u8] = slice!(b"abcde".as_slice(), FromEnd(1..4));
+    assert_eq!(BYTES_2, slice!(b"abcde", 1..4));
+
+    const STR: &str = slice!("a✨💖", FromEnd(0..4));
+    assert_eq!(STR, slice!("a✨💖", 4..8));
+
+    const STR_2: &str = slice!("a✨💖", FromEnd(4..7));
+    assert_eq!(STR_2, slice!("a✨💖", 1..4));
+
+    const EMPTY: &str = slice!("a✨💖", FromEnd(4..4));
+    assert_eq!(EMPTY, "");
+
+    slice_fail!(&[u8], b"abcde", FromEnd(0..6));
+    slice_fail!(&[u8], b"abcde", FromEnd(Range { start: 3, end: 2 }));
+    slice_fail!(&str, "a✨💖", FromEnd(0..5));
+}
+
 #[test]
 fn slice_split_at() {
     const SPLIT: (&str, &str) = slice_split_at!("abcde", 3);
@@ -74,64 +510,1531 @@ fn slice_split_at() {
 }
 
 #[test]
-fn eq() {
-    const EMPTY: bool = slice_eq!("", "");
-    assert_eq!(EMPTY, true);
+fn slice_split_at_checked() {
+    const BYTES_START: Option<(&[u8], &[u8])> = slice_split_at_checked!(b"abc", 0);
+    assert_eq!(BYTES_START, Some((&b""[..], &b"abc"[..])));
 
-    const EQ: bool = slice_eq!("hi", "hi");
-    assert_eq!(EQ, true);
+    const BYTES_END: Option<(&[u8], &[u8])> = slice_split_at_checked!(b"abc".as_slice(), 3);
+    assert_eq!(BYTES_END, Some((&b"abc"[..], &b""[..])));
 
-    const NEQ: bool = slice_eq!("hi", "ho");
-    assert_eq!(NEQ, false);
+    const BYTES_PAST_END: Option<(&[u8], &[u8])> = slice_split_at_checked!(b"abc", 4);
+    assert_eq!(BYTES_PAST_END, None);
 
-    const NEQ2: bool = slice_eq!("hi", "hello");
-    assert_eq!(NEQ2, false);
+    const STR_START: Option<(&str, &str)> = slice_split_at_checked!("✨💖", 0);
+    assert_eq!(STR_START, Some(("", "✨💖")));
+
+    const STR_END: Option<(&str, &str)> = slice_split_at_checked!("✨💖", 7);
+    assert_eq!(STR_END, Some(("✨💖", "")));
+
+    const STR_PAST_END: Option<(&str, &str)> = slice_split_at_checked!("✨💖", 8);
+    assert_eq!(STR_PAST_END, None);
+
+    const STR_CODEPOINT: Option<(&str, &str)> = slice_split_at_checked!("✨💖", 6);
+    assert_eq!(STR_CODEPOINT, None);
+
+    const STR_BEFORE_TAIL: Option<(&str, &str)> = slice_split_at_checked!("a✨", 1);
+    assert_eq!(STR_BEFORE_TAIL, Some(("a", "✨")));
+
+    const EMPTY: Option<(&str, &str)> = slice_split_at_checked!("", 0);
+    assert_eq!(EMPTY, Some(("", "")));
+
+    const EMPTY_SPLIT: (&str, &str) = slice_split_at!("", 0);
+    assert_eq!(EMPTY_SPLIT, ("", ""));
 }
 
 #[test]
-fn cmp() {
-    const CMP1: Ordering = slice_cmp!("hi", "hi");
-    assert_eq!(CMP1, Ordering::Equal);
+fn slice_split_at_ratio() {
+    const PARTS: (&[i32], &[i32]) = slice_split_at_ratio!(&[0, 1, 2, 3, 4, 5, 6, 7, 8, 9], 3, 10);
+    assert_eq!((PARTS.0.len(), PARTS.1.len()), (3, 7));
+    const HALF: (&str, &str) = slice_split_at_ratio!("abcde", 1, 2);
+    assert_eq!(HALF, ("ab", "cde"));
+    const ALL: (&[u8], &[u8]) = slice_split_at_ratio!(b"abc".as_slice(), 1, 1);
+    assert_eq!(ALL, (&b"abc"[..], &b""[..]));
+    let big = [0u8; 10];
+    assert_eq!(
+        slice_split_at_ratio!(&big, usize::MAX, usize::MAX).0.len(),
+        10
+    );
+}
 
-    const CMP2: Ordering = slice_cmp!("hi", "ho");
-    assert_eq!(CMP2, Ordering::Less);
+#[test]
+#[should_panic(expected = "ratio denominator must be non-zero")]
+fn slice_split_at_ratio_zero() {
+    let _ = slice_split_at_ratio!(b"abc", 1, 0);
+}
 
-    const CMP3: Ordering = slice_cmp!("ho", "hi");
-    assert_eq!(CMP3, Ordering::Greater);
+#[test]
+fn slice_checked_split_at() {
+    const SPLIT: Result<(&str, &str), SliceError> = slice_checked_split_at!("✨💖", 3);
+    assert_eq!(SPLIT, Ok(("✨", "💖")));
 
-    const CMP4: Ordering = slice_cmp!("h", "hi");
-    assert_eq!(CMP4, Ordering::Less);
+    const SPLIT_END: Result<(&str, &str), SliceError> = slice_checked_split_at!("✨💖", 7);
+    assert_eq!(SPLIT_END, Ok(("✨💖", "")));
 
-    const CMP5: Ordering = slice_cmp!("hi", "h");
-    assert_eq!(CMP5, Ordering::Greater);
+    const OUT_OF_RANGE: Result<(&str, &str), SliceError> = slice_checked_split_at!("✨💖", 8);
+    assert_eq!(OUT_OF_RANGE, Err(SliceError::OutOfRange));
+
+    const SPLITS_CODEPOINT: Result<(&str, &str), SliceError> = slice_checked_split_at!("✨💖", 2);
+    assert_eq!(SPLITS_CODEPOINT, Err(SliceError::SplitsCodepoint));
+
+    const BYTES: Result<(&[u8], &[u8]), SliceError> = slice_checked_split_at!(b"abc", 1);
+    assert_eq!(BYTES, Ok((&b"a"[..], &b"bc"[..])));
+
+    const BYTES_OUT_OF_RANGE: Result<(&[u8], &[u8]), SliceError> =
+        slice_checked_split_at!(b"abc", 4);
+    assert_eq!(BYTES_OUT_OF_RANGE, Err(SliceError::OutOfRange));
 }
 
+#[cfg(feature = "std")]
 #[test]
-fn prefix() {
-    const STARTS_WITH: bool = slice_starts_with!("abcde", "ab");
-    assert_eq!(STARTS_WITH, true);
+fn slice_error_std() {
+    use alloc::{boxed::Box, string::ToString};
+    use std::error::Error;
 
-    const NOT_STARTS_WITH: bool = slice_starts_with!("abcde", "aba");
-    assert_eq!(NOT_STARTS_WITH, false);
+    let err: Box<dyn Error> = Box::new(SliceError::SplitsCodepoint);
+    assert_eq!(err.to_string(), "slice splits utf-8 codepoint");
+    assert!(err.source().is_none());
+}
 
-    const STRIPPED: Option<&str> = slice_strip_prefix!("abcde", "abc");
-    assert_eq!(STRIPPED, Some("de"));
+#[test]
+fn slice_split_at_many() {
+    const SPLIT_1: (&[u8], &[u8]) = slice_split_at_many!(b"aabbbcc", [2]);
+    assert_eq!(SPLIT_1, (&b"aa"[..], &b"bbbcc"[..]));
 
-    const NOT_STRIPPED: Option<&str> = slice_strip_prefix!("abcde", "ace");
-    assert_eq!(NOT_STRIPPED, None);
+    const SPLIT_2: (&[u8], &[u8], &[u8]) = slice_split_at_many!(b"aabbbcc", [2, 5]);
+    assert_eq!(SPLIT_2, (&b"aa"[..], &b"bbb"[..], &b"cc"[..]));
+
+    const SPLIT_3: (&str, &str, &str, &str) = slice_split_at_many!("aabbbcc", [1, 1, 6]);
+    assert_eq!(SPLIT_3, ("a", "", "abbbc", "c"));
+
+    const SPLIT_4: (&str, &str, &str, &str, &str) = slice_split_at_many!("abcde", [0, 1, 2, 4]);
+    assert_eq!(SPLIT_4, ("", "a", "b", "cd", "e"));
 }
 
 #[test]
-fn suffix() {
-    const ENDS_WITH: bool = slice_ends_with!("abcde", "de");
-    assert_eq!(ENDS_WITH, true);
+#[should_panic]
+fn slice_split_at_many_descending() {
+    let _ = slice_split_at_many!(b"aabbbcc", [5, 2]);
+}
 
-    const NOT_ENDS_WITH: bool = slice_ends_with!("abcde", "ee");
-    assert_eq!(NOT_ENDS_WITH, false);
+#[test]
+fn slice_split_around() {
+    const SPLIT: (&[u8], &[u8], &[u8]) = slice_split_around!(b"abcde", 1..3);
+    assert_eq!(SPLIT, (&b"a"[..], &b"bc"[..], &b"de"[..]));
 
-    const STRIPPED: Option<&str> = slice_strip_suffix!("abcde", "cde");
-    assert_eq!(STRIPPED, Some("ab"));
+    const SPLIT_STR: (&str, &str, &str) = slice_split_around!("✨💖✨", 3..7);
+    assert_eq!(SPLIT_STR, ("✨", "💖", "✨"));
 
-    const NOT_STRIPPED: Option<&str> = slice_strip_suffix!("abcde", "cdf");
-    assert_eq!(NOT_STRIPPED, None);
+    const TRY_SPLIT: Option<(&[u8], &[u8], &[u8])> = slice_try_split_around!(b"abcde", 3..9);
+    assert_eq!(TRY_SPLIT, None);
+
+    const TRY_SPLIT_2: Option<(&str, &str, &str)> = slice_try_split_around!("✨💖", 1..3);
+    assert_eq!(TRY_SPLIT_2, None);
+}
+
+#[test]
+fn slice_copy() {
+    const COPY: u8 = slice_copy!(b"abc", 1);
+    assert_eq!(COPY, b'b');
+
+    const TRY_COPY: Option<i32> = slice_try_copy!(&[1, 2, 3], 2);
+    assert_eq!(TRY_COPY, Some(3));
+
+    const TRY_COPY_OUT_OF_RANGE: Option<u8> = slice_try_copy!(b"abc".as_slice(), 3);
+    assert_eq!(TRY_COPY_OUT_OF_RANGE, None);
+
+    let n = 10;
+    let copied = slice_copy!(&[n, n + 1, n + 2], 1);
+    let try_copied = slice_try_copy!(&[n, n + 1, n + 2], 2);
+    assert_eq!(copied, 11);
+    assert_eq!(try_copied, Some(12));
+}
+
+#[test]
+#[should_panic]
+fn slice_copy_out_of_range() {
+    let _ = slice_copy!(b"abc".as_slice(), 3);
+}
+
+#[test]
+fn slice_get_or() {
+    const TABLE: [u8; 3] = [1, 2, 4];
+
+    const IN_RANGE: u8 = slice_get_or!(&TABLE, 2, 0);
+    assert_eq!(IN_RANGE, 4);
+
+    const OUT_OF_RANGE: u8 = slice_get_or!(&TABLE, 3, 0);
+    assert_eq!(OUT_OF_RANGE, 0);
+
+    const SLICE_IN_RANGE: char = slice_get_or!("abc".as_bytes(), 0, 0) as char;
+    assert_eq!(SLICE_IN_RANGE, 'a');
+
+    const SLICE_OUT_OF_RANGE: u8 = slice_get_or!(b"".as_slice(), 0, b'?');
+    assert_eq!(SLICE_OUT_OF_RANGE, b'?');
+}
+
+#[test]
+fn slice_concat_many() {
+    const CONCAT: [u8; 7] = slice_concat_many!([b"ab", b"c", b"defg"]);
+    assert_eq!(&CONCAT, b"abcdefg");
+
+    const EMPTY_PART: [u8; 4] = slice_concat_many!([b"ab", b"", b"cd"]);
+    assert_eq!(&EMPTY_PART, b"abcd");
+
+    const PART: &[u16] = &[1, 2];
+    const WIDE: [u16; 5] = slice_concat_many!([PART, &[3], &[4, 5]]);
+    assert_eq!(WIDE, [1, 2, 3, 4, 5]);
+
+    const EMPTY: [u8; 0] = slice_concat_many!([b""]);
+    assert_eq!(EMPTY, []);
+}
+
+#[test]
+fn slice_interleave() {
+    const INTERLEAVED: [i32; 6] = slice_interleave!(&[1, 2, 3], &[4, 5, 6]);
+    assert_eq!(INTERLEAVED, [1, 4, 2, 5, 3, 6]);
+
+    const BYTES: [u8; 4] = slice_interleave!(b"ac", b"bd");
+    assert_eq!(&BYTES, b"abcd");
+
+    const EMPTY: [u8; 0] = slice_interleave!(b"", b"");
+    assert_eq!(EMPTY, []);
+}
+
+#[test]
+fn slice_pad() {
+    const PAD_END: [u8; 4] = slice_pad_end!(b"ab", 4, b' ');
+    assert_eq!(PAD_END, *b"ab  ");
+
+    const PAD_START: [u8; 4] = slice_pad_start!(b"ab", 4, b' ');
+    assert_eq!(PAD_START, *b"  ab");
+
+    const PAD_EXACT: [u16; 2] = slice_pad_end!(&[1, 2], 2, 0);
+    assert_eq!(PAD_EXACT, [1, 2]);
+
+    const PAD_EMPTY: [char; 3] = slice_pad_start!(&[], 3, '-');
+    assert_eq!(PAD_EMPTY, ['-'; 3]);
+}
+
+#[test]
+#[should_panic(expected = "slice is longer than the padded length")]
+fn slice_pad_too_long() {
+    let _: [u8; 2] = slice_pad_end!(b"abc", 2, b' ');
+}
+
+#[test]
+fn slice_chunks_array() {
+    const CHUNKS: Option<[&[i32; 2]; 2]> = slice_chunks_array!(&[1, 2, 3, 4, 5], 2, 2);
+    assert_eq!(CHUNKS, Some([&[1, 2], &[3, 4]]));
+
+    const EXACT: Option<[&[u8; 3]; 2]> = slice_chunks_array!(b"abcdef", 3, 2);
+    assert_eq!(EXACT, Some([b"abc", b"def"]));
+
+    const TOO_SHORT: Option<[&[u8; 3]; 2]> = slice_chunks_array!(b"abcde".as_slice(), 3, 2);
+    assert_eq!(TOO_SHORT, None);
+
+    const NONE: Option<[&[u8; 3]; 0]> = slice_chunks_array!(b"", 3, 0);
+    assert_eq!(NONE, Some([]));
+
+    const OVERFLOW: Option<[&[(); usize::MAX / 2 + 1]; 2]> =
+        slice_chunks_array!(&[(); 0], usize::MAX / 2 + 1, 2);
+    assert!(OVERFLOW.is_none());
+}
+
+#[test]
+fn str_first_bytes() {
+    const MAGIC: Option<&[u8; 3]> = str_first_bytes!("PNG...", 3);
+    assert_eq!(MAGIC, Some(b"PNG"));
+    const SHORT: Option<&[u8; 3]> = str_first_bytes!("PN", 3);
+    assert_eq!(SHORT, None);
+    const SPLIT: Option<&[u8; 2]> = str_first_bytes!("✨", 2);
+    assert_eq!(SPLIT, Some(&[0xe2, 0x9c]));
+    const EMPTY: Option<&[u8; 0]> = str_first_bytes!("", 0);
+    assert_eq!(EMPTY, Some(&[]));
+}
+
+#[test]
+fn str_reverse_ascii() {
+    const REVERSED: [u8; 3] = str_reverse_ascii!("abc");
+    assert_eq!(&REVERSED, b"cba");
+    const EMPTY: [u8; 0] = str_reverse_ascii!("");
+    assert_eq!(EMPTY, []);
+
+    const TRY_REVERSED: Result<[u8; 3], &str> = str_try_reverse_ascii!("abc");
+    assert_eq!(TRY_REVERSED, Ok(*b"cba"));
+    const MULTIBYTE: Result<[u8; 4], &str> = str_try_reverse_ascii!("a✨");
+    assert_eq!(MULTIBYTE, Err("string isn't ascii"));
+}
+
+#[test]
+#[should_panic(expected = "string isn't ascii")]
+fn str_reverse_ascii_multibyte() {
+    let _ = str_reverse_ascii!("a✨");
+}
+
+#[test]
+fn str_lines_count() {
+    const TRAILING: usize = str_lines_count!("a\nb\n");
+    assert_eq!(TRAILING, 2);
+    const NO_TRAILING: usize = str_lines_count!("a\nb");
+    assert_eq!(NO_TRAILING, 2);
+    const CRLF: usize = str_lines_count!("a\r\n\r\nb\r\n");
+    assert_eq!(CRLF, 3);
+    const EMPTY: usize = str_lines_count!("");
+    assert_eq!(EMPTY, 0);
+    for s in ["", "\n", "\n\n", "a", "a\n\nb", "a\r\nb\r\n", "\r\n", "a\r"] {
+        assert_eq!(str_lines_count!(s), s.lines().count(), "{s:?}");
+    }
+}
+
+#[test]
+fn str_capitalize_ascii() {
+    const HELLO: [u8; 5] = str_capitalize_ascii!("hello");
+    assert_eq!(&HELLO, b"Hello");
+    const DIGITS: [u8; 6] = str_capitalize_ascii!("123abc");
+    assert_eq!(&DIGITS, b"123abc");
+    const UPPER: [u8; 2] = str_capitalize_ascii!("Ab");
+    assert_eq!(&UPPER, b"Ab");
+    const NON_ASCII: [u8; 3] = str_capitalize_ascii!("äb");
+    assert_eq!(&NON_ASCII, "äb".as_bytes());
+    const EMPTY: [u8; 0] = str_capitalize_ascii!("");
+    assert_eq!(EMPTY, []);
+}
+
+#[test]
+fn str_concat() {
+    const PATH: StrBuf<7> = str_concat!("foo", "/", "bar");
+    assert_eq!(PATH.as_str(), "foo/bar");
+    const SPARKLES: StrBuf<8> = str_concat!("a", "✨", "", "✨", "b");
+    const SPARKLES_STR: &str = SPARKLES.as_str();
+    assert_eq!(SPARKLES_STR, "a✨✨b");
+    const SINGLE: StrBuf<0> = str_concat!("");
+    assert_eq!(SINGLE.as_str(), "");
+}
+
+#[test]
+fn str_is_ascii_identifier() {
+    const VALID: bool = str_is_ascii_identifier!("_field1");
+    assert_eq!(VALID, true);
+    const LEADING_DIGIT: bool = str_is_ascii_identifier!("1field");
+    assert_eq!(LEADING_DIGIT, false);
+    const EMPTY: bool = str_is_ascii_identifier!("");
+    assert_eq!(EMPTY, false);
+    assert!(str_is_ascii_identifier!("CamelCase"));
+    assert!(str_is_ascii_identifier!("_"));
+    assert!(!str_is_ascii_identifier!("kebab-case"));
+    assert!(!str_is_ascii_identifier!("naïve"));
+}
+
+#[test]
+fn str_truncate_to() {
+    const SPLIT: &str = str_truncate_to!("ab✨c", 4);
+    assert_eq!(SPLIT, "ab");
+    const BOUNDARY: &str = str_truncate_to!("ab✨c", 5);
+    assert_eq!(BOUNDARY, "ab✨");
+    const LONGER: &str = str_truncate_to!("ab✨c", 100);
+    assert_eq!(LONGER, "ab✨c");
+    const ZERO: &str = str_truncate_to!("✨", 0);
+    assert_eq!(ZERO, "");
+    assert_eq!(str_truncate_to!("✨", 2), "");
+}
+
+#[test]
+fn slice_to_unique_array() {
+    const UNIQUE: Result<([i32; 4], usize), &str> = slice_to_unique_array!(&[1i32, 2, 2, 3, 1], 4);
+    assert_eq!(UNIQUE, Ok(([1, 2, 3, 0], 3)));
+    const EXACT: Result<([char; 2], usize), &str> = slice_to_unique_array!(&['b', 'a', 'b'], 2);
+    assert_eq!(EXACT, Ok((['b', 'a'], 2)));
+    const EMPTY: Result<([bool; 1], usize), &str> = slice_to_unique_array!(&[] as &[bool], 1);
+    assert_eq!(EMPTY, Ok(([false], 0)));
+    const TOO_MANY: Result<([u8; 2], usize), &str> = slice_to_unique_array!(b"abc", 2);
+    assert!(TOO_MANY.is_err());
+}
+
+#[test]
+fn str_join() {
+    const LIST: StrBuf<7> = str_join!(["a", "b", "c"], ", ");
+    assert_eq!(LIST.as_str(), "a, b, c");
+    const SEP: &str = "::";
+    const PATH: StrBuf<14> = str_join!(["core", "str", "✨"], SEP);
+    assert_eq!(PATH.as_str(), "core::str::✨");
+    const SINGLE: StrBuf<3> = str_join!(["one"], ", ");
+    assert_eq!(SINGLE.as_str(), "one");
+}
+
+#[test]
+fn str_skip_ascii_whitespace() {
+    const TOKEN: &str = str_skip_ascii_whitespace!("   tok");
+    assert_eq!(TOKEN, "tok");
+    const SKIPPED: usize = str_leading_whitespace_len!("   tok");
+    assert_eq!(SKIPPED, 3);
+    const MIXED: &str = str_skip_ascii_whitespace!("\t\r\n\x0c tok en ");
+    assert_eq!(MIXED, "tok en ");
+    const ALL: usize = str_leading_whitespace_len!(" \n ");
+    assert_eq!(ALL, 3);
+    // non-ASCII whitespace isn't skipped
+    const NBSP: &str = str_skip_ascii_whitespace!(" \u{a0}tok");
+    assert_eq!(NBSP, "\u{a0}tok");
+}
+
+#[test]
+fn str_line_at() {
+    const TEXT: &str = "one\ntwo\nthree";
+    const MIDDLE: Option<&str> = str_line_at!(TEXT, 1);
+    assert_eq!(MIDDLE, Some("two"));
+    const OUT_OF_RANGE: Option<&str> = str_line_at!(TEXT, 3);
+    assert_eq!(OUT_OF_RANGE, None);
+    const CRLF: Option<&str> = str_line_at!("a\r\n\r\nb", 1);
+    assert_eq!(CRLF, Some(""));
+    for s in [
+        "", "\n", "a\n", "a\n\nb\n", "\r\n", "a\r", "a\r\nb\r", "✨\n✨",
+    ] {
+        for n in 0..4 {
+            assert_eq!(str_line_at!(s, n), s.lines().nth(n), "{s:?} {n}");
+        }
+    }
+}
+
+#[test]
+fn str_lines_inclusive() {
+    const fn second_line(s: &str) -> Option<&str> {
+        let mut lines = str_lines_inclusive!(s);
+        lines.next();
+        lines.next()
+    }
+    const SECOND: Option<&str> = second_line("one\r\ntwo\nthree");
+    assert_eq!(SECOND, Some("two\n"));
+
+    let lines: Vec<&str> = str_lines_inclusive!("one\r\ntwo\nthree").collect();
+    assert_eq!(lines, ["one\r\n", "two\n", "three"]);
+    for s in ["", "\n", "a\n", "\n\na", "a\r\nb\r", "✨\n✨"] {
+        assert!(str_lines_inclusive!(s).eq(s.split_inclusive('\n')), "{s:?}");
+    }
+}
+
+#[test]
+fn str_chars_eq() {
+    const EQ: bool = str_chars_eq!("a✨", ['a', '✨']);
+    assert_eq!(EQ, true);
+    const SHORT: bool = str_chars_eq!("a✨", ['a']);
+    assert_eq!(SHORT, false);
+    const LONG: bool = str_chars_eq!("a✨", ['a', '✨', 'b']);
+    assert_eq!(LONG, false);
+    const NE: bool = str_chars_eq!("a✨", ['a', '*']);
+    assert_eq!(NE, false);
+    assert!(str_chars_eq!("", []));
+}
+
+#[test]
+fn slice_eq_bytes_str() {
+    const BODY: &[u8] = b"OK";
+    const EQ: bool = slice_eq_bytes_str!(BODY, "OK");
+    assert_eq!(EQ, true);
+    const ARRAY: bool = slice_eq_bytes_str!(b"OK", "OK");
+    assert_eq!(ARRAY, true);
+    const NE: bool = slice_eq_bytes_str!(BODY, "OK\n");
+    assert_eq!(NE, false);
+    assert!(slice_eq_bytes_str!("✨".as_bytes(), "✨"));
+}
+
+#[test]
+fn str_bytes_cmp() {
+    const LESS: Ordering = str_bytes_cmp!("abc", b"abd");
+    assert_eq!(LESS, Ordering::Less);
+    const BODY: &[u8] = b"abc";
+    const EQUAL: Ordering = str_bytes_cmp!("abc", BODY);
+    assert_eq!(EQUAL, Ordering::Equal);
+    const PREFIX: Ordering = str_bytes_cmp!("abc", b"ab");
+    assert_eq!(PREFIX, Ordering::Greater);
+    assert_eq!(str_bytes_cmp!("✨", &[0xe2, 0x9c, 0xa8]), Ordering::Equal);
+}
+
+#[test]
+fn cstr_cmp() {
+    const LESS: Ordering = cstr_cmp!(b"ab\0xx", b"ac\0yy");
+    assert_eq!(LESS, Ordering::Less);
+    const AFTER_NUL: Ordering = cstr_cmp!(b"ab\0xx", b"ab\0yy");
+    assert_eq!(AFTER_NUL, Ordering::Equal);
+    const PREFIX: Ordering = cstr_cmp!(b"ab\0", b"a\0b");
+    assert_eq!(PREFIX, Ordering::Greater);
+    const NO_NUL: &[u8] = b"ab";
+    const UNTERMINATED: Ordering = cstr_cmp!(NO_NUL, b"ab\0c");
+    assert_eq!(UNTERMINATED, Ordering::Equal);
+}
+
+#[test]
+fn slice_eq_ignore_ascii_case() {
+    const CHARS: bool = slice_eq_ignore_ascii_case!(&['H', 'I'], &['h', 'i']);
+    assert_eq!(CHARS, true);
+    const NON_ASCII: bool = slice_eq_ignore_ascii_case!(&['Ä'], &['ä']);
+    assert_eq!(NON_ASCII, false);
+    const LEN: bool = slice_eq_ignore_ascii_case!(&['a'], &['a', 'b']);
+    assert_eq!(LEN, false);
+    const BYTES: bool = slice_eq_ignore_ascii_case!(b"Content-Type", b"content-TYPE");
+    assert_eq!(BYTES, true);
+    const STR: bool = slice_eq_ignore_ascii_case!("GET", "Get");
+    assert_eq!(STR, true);
+    assert!(!slice_eq_ignore_ascii_case!(b"[", b"{"));
+}
+
+#[test]
+fn slice_eq_wildcard() {
+    const ABC: bool = slice_eq_wildcard!(b"abc", b"a_c", b'_');
+    assert_eq!(ABC, true);
+    const AXC: bool = slice_eq_wildcard!(b"axc", b"a_c", b'_');
+    assert_eq!(AXC, true);
+    const MISMATCH: bool = slice_eq_wildcard!(b"abd", b"a_c", b'_');
+    assert_eq!(MISMATCH, false);
+    const LEN: bool = slice_eq_wildcard!(b"abcd", b"a_c", b'_');
+    assert_eq!(LEN, false);
+    // wildcards are only special in the pattern
+    assert!(!slice_eq_wildcard!(b"a_c", b"abc", b'_'));
+}
+
+#[test]
+fn str_eq_ignore_ascii_case() {
+    const EQ: bool = str_eq_ignore_ascii_case!("Content-Type", "CONTENT-type");
+    assert_eq!(EQ, true);
+    const NE: bool = str_eq_ignore_ascii_case!("Content-Type", "Content-Typf");
+    assert_eq!(NE, false);
+    const LEN: bool = str_eq_ignore_ascii_case!("Content", "Content-Type");
+    assert_eq!(LEN, false);
+    const NON_ASCII: bool = str_eq_ignore_ascii_case!("Ä", "ä");
+    assert_eq!(NON_ASCII, false);
+    assert!(str_eq_ignore_ascii_case!("", ""));
+}
+
+#[test]
+fn slice_get_many() {
+    const ELEMENTS: Option<[&i32; 2]> = slice_get_many!(&[10, 20, 30], [0, 2]);
+    assert_eq!(ELEMENTS, Some([&10, &30]));
+    const REPEATED: Option<[&u8; 3]> = slice_get_many!(b"abc", [2, 0, 2]);
+    assert_eq!(REPEATED, Some([&b'c', &b'a', &b'c']));
+    const OUT_OF_RANGE: Option<[&u8; 2]> = slice_get_many!(b"abc", [0, 3]);
+    assert_eq!(OUT_OF_RANGE, None);
+}
+
+#[test]
+fn array_from() {
+    const ARRAY: [i32; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+    const REST: &[i32; 6] = array_from!(&ARRAY, 2);
+    assert_eq!(REST, &[3, 4, 5, 6, 7, 8]);
+    const ALL: &[i32; 8] = array_from!(&ARRAY, 0);
+    assert_eq!(ALL, &ARRAY);
+    const NONE: &[i32; 0] = array_from!(&ARRAY, 8);
+    assert_eq!(NONE, &[]);
+}
+
+#[test]
+fn slice_cmp_array_values() {
+    const REVERSED: bool = slice_eq!(slice_sort_desc!([1u8, 2, 3]), [3u8, 2, 1]);
+    assert_eq!(REVERSED, true);
+    const CONCAT: bool = slice_eq!(slice_concat_many!([b"ab", b"c"]), b"abc");
+    assert_eq!(CONCAT, true);
+    const CMP: Ordering = slice_cmp!(b"abd", slice_sort!(*b"dab"));
+    assert_eq!(CMP, Ordering::Equal);
+    const UNSORTED: bool = slice_eq!(slice_sort!([2i32, 1]), [2i32, 1]);
+    assert_eq!(UNSORTED, false);
+    assert!(slice_eq!([true, false], [true, false]));
+}
+
+#[test]
+fn slice_cmp_nested() {
+    const A: [[u8; 2]; 3] = [[1, 2], [3, 4], [5, 6]];
+    const B: [[u8; 2]; 3] = [[1, 2], [3, 4], [5, 7]];
+    const EQ: bool = slice_eq!(&A, &B);
+    assert_eq!(EQ, false);
+    const CMP: Ordering = slice_cmp!(&A, &B);
+    assert_eq!(CMP, Ordering::Less);
+    const SELF: bool = slice_eq!(&A, &A);
+    assert_eq!(SELF, true);
+    const PREFIX: Ordering = slice_cmp!(&A, &[[1u8, 2], [3, 4]]);
+    assert_eq!(PREFIX, Ordering::Greater);
+    const SLICE: Ordering = slice_cmp!(B.as_slice(), A.as_slice());
+    assert_eq!(SLICE, Ordering::Greater);
+    assert_eq!(slice_cmp!(&[['b'; 1]], &[['a'; 1]; 2]), Ordering::Greater);
+}
+
+#[test]
+fn slice_first_diff() {
+    const DIFF: Option<(usize, Ordering)> = slice_first_diff!(b"abc", b"abd");
+    assert_eq!(DIFF, Some((2, Ordering::Less)));
+    const GREATER: Option<(usize, Ordering)> = slice_first_diff!(&[3i32, -1], &[3, -2]);
+    assert_eq!(GREATER, Some((1, Ordering::Greater)));
+    const PREFIX: Option<(usize, Ordering)> = slice_first_diff!("ab", "abc");
+    assert_eq!(PREFIX, Some((2, Ordering::Less)));
+    const LONGER: Option<(usize, Ordering)> = slice_first_diff!(&[true, false], &[true]);
+    assert_eq!(LONGER, Some((1, Ordering::Greater)));
+    const EQUAL: Option<(usize, Ordering)> = slice_first_diff!("abc", "abc");
+    assert_eq!(EQUAL, None);
+}
+
+#[test]
+fn slice_shortlex_cmp() {
+    const SHORTLEX: Ordering = slice_shortlex_cmp!("b", "aa");
+    assert_eq!(SHORTLEX, Ordering::Less);
+    const LEX: Ordering = slice_cmp!("b", "aa");
+    assert_eq!(LEX, Ordering::Greater);
+
+    assert_eq!(slice_shortlex_cmp!("aa", "b"), Ordering::Greater);
+    assert_eq!(slice_shortlex_cmp!("ab", "aa"), Ordering::Greater);
+    assert_eq!(slice_shortlex_cmp!("ab", "ab"), Ordering::Equal);
+    assert_eq!(slice_shortlex_cmp!(&[2u8], &[1u8, 1]), Ordering::Less);
+    assert_eq!(slice_shortlex_cmp!(b"ab", b"ba"), Ordering::Less);
+    assert_eq!(slice_shortlex_cmp!(&[0i32; 0], &[0i32; 0]), Ordering::Equal);
+}
+
+#[test]
+fn slice_rchunks_array() {
+    type Chunk = &'static [i32; 2];
+    const fn chunks() -> ([Option<Chunk>; 3], &'static [i32]) {
+        let mut chunks = slice_rchunks_array!(&[1, 2, 3, 4, 5], 2);
+        (
+            [chunks.next(), chunks.next(), chunks.next()],
+            chunks.remainder(),
+        )
+    }
+    const CHUNKS: ([Option<Chunk>; 3], &[i32]) = chunks();
+    assert_eq!(CHUNKS, ([Some(&[4, 5]), Some(&[2, 3]), None], &[1][..]));
+
+    let chunks = slice_rchunks_array!(b"abcdef", 3);
+    assert_eq!(chunks.remainder(), b"");
+    assert_eq!(chunks.collect::<Vec<_>>(), [b"def", b"abc"]);
+
+    let mut chunks = slice_rchunks_array!(b"ab", 3);
+    assert_eq!(chunks.remainder(), b"ab");
+    assert_eq!(chunks.next(), None);
+}
+
+#[test]
+fn slice_sum_float() {
+    const SUM: f32 = slice_sum_float!(&[1.0f32, 2.0, 3.0]);
+    assert_eq!(SUM, 6.0);
+    const SUM64: f64 = slice_sum_float!([0.5f64, 0.25, -1.0].as_slice());
+    assert_eq!(SUM64, -0.25);
+    const EMPTY: f64 = slice_sum_float!(&[0.0f64; 0]);
+    assert_eq!(EMPTY.to_bits(), (-0.0f64).to_bits());
+    const NEG_ZERO: f32 = slice_sum_float!(&[-0.0f32]);
+    assert_eq!(NEG_ZERO.to_bits(), (-0.0f32).to_bits());
+    const ZERO: f32 = slice_sum_float!(&[-0.0f32, 0.0]);
+    assert_eq!(ZERO.to_bits(), 0.0f32.to_bits());
+    // addition is in index order, so rounding depends on the order of the elements
+    assert_eq!(slice_sum_float!(&[1.0e16f64, 1.0, 1.0]), 1.0e16);
+    assert_eq!(slice_sum_float!(&[1.0f64, 1.0, 1.0e16]), 1.0e16 + 2.0);
+}
+
+#[test]
+fn slice_bits_eq() {
+    const NAN: bool = slice_bits_eq!(&[1.0f32, f32::NAN], &[1.0f32, f32::NAN]);
+    assert_eq!(NAN, true);
+    const ZERO: bool = slice_bits_eq!(&[0.0f64, 1.0], &[-0.0f64, 1.0]);
+    assert_eq!(ZERO, false);
+    const TABLE: &[f64] = &[0.5, -0.0, f64::INFINITY];
+    const SAME: bool = slice_bits_eq!(TABLE, &[0.5f64, -0.0, f64::INFINITY]);
+    assert_eq!(SAME, true);
+    const LEN: bool = slice_bits_eq!(TABLE, &[0.5f64, -0.0]);
+    assert_eq!(LEN, false);
+}
+
+#[test]
+fn slice_count_bits() {
+    const ONES: usize = slice_count_ones!(b"\xff\x00\x0f");
+    assert_eq!(ONES, 12);
+    const ZEROS: usize = slice_count_zeros!(b"\xff\x00\x0f");
+    assert_eq!(ZEROS, 12);
+    const EMPTY: usize = slice_count_ones!(&[] as &[u8]);
+    assert_eq!(EMPTY, 0);
+    const WIDE: usize = slice_count_ones!(&[-1i64, 1, u32::MAX as i64]);
+    assert_eq!(WIDE, 97);
+    const WIDE_ZEROS: usize = slice_count_zeros!(&[u128::MAX, 0]);
+    assert_eq!(WIDE_ZEROS, 128);
+}
+
+#[test]
+fn u64_to_str() {
+    const ZERO: ([u8; 20], usize) = u64_to_str!(0);
+    assert_eq!(decimal_as_str!(&ZERO), "0");
+    const DIGITS: ([u8; 20], usize) = u64_to_str!(12345);
+    assert_eq!(DIGITS.1, 15);
+    const STR: &str = decimal_as_str!(&DIGITS);
+    assert_eq!(STR, "12345");
+    const MAX: ([u8; 20], usize) = u64_to_str!(u64::MAX);
+    assert_eq!(decimal_as_str!(&MAX), "18446744073709551615");
+    for value in [1, 9, 10, 99, 100, 1 << 32, 10_000_000_000_000_000_000] {
+        assert_eq!(decimal_as_str!(&u64_to_str!(value)), value.to_string());
+    }
+}
+
+#[test]
+fn i64_to_str() {
+    const NEGATIVE: ([u8; 21], usize) = i64_to_str!(-12345);
+    const STR: &str = decimal_as_str!(&NEGATIVE);
+    assert_eq!(STR, "-12345");
+    const ZERO: ([u8; 21], usize) = i64_to_str!(0);
+    assert_eq!(decimal_as_str!(&ZERO), "0");
+    const MIN: ([u8; 21], usize) = i64_to_str!(i64::MIN);
+    assert_eq!(MIN.1, 1);
+    assert_eq!(decimal_as_str!(&MIN), "-9223372036854775808");
+    for value in [1, -1, 10, -10, i64::MAX, i64::MIN + 1] {
+        assert_eq!(decimal_as_str!(&i64_to_str!(value)), value.to_string());
+    }
+}
+
+#[test]
+fn slice_longest_run() {
+    const RUN: (usize, usize) = slice_longest_run!(b"aabaaab", b'a');
+    assert_eq!(RUN, (3, 3));
+    const FIRST: (usize, usize) = slice_longest_run!(&[true, true, false, true, true], true);
+    assert_eq!(FIRST, (0, 2));
+    const END: (usize, usize) = slice_longest_run!(&[1i32, 0, 0], 0);
+    assert_eq!(END, (1, 2));
+    const NONE: (usize, usize) = slice_longest_run!(&['a', 'b'], 'c');
+    assert_eq!(NONE, (0, 0));
+    assert_eq!(slice_longest_run!(&[0u64; 0], 0), (0, 0));
+}
+
+#[test]
+fn slice_chunks_exact() {
+    const fn count_and_remainder() -> (usize, usize) {
+        let mut chunks = slice_chunks_exact!(b"abcdefgh", 3);
+        let mut count = 0;
+        while let Some(chunk) = chunks.next() {
+            assert!(chunk.len() == 3);
+            count += 1;
+        }
+        (count, chunks.remainder().len())
+    }
+    const COUNT: (usize, usize) = count_and_remainder();
+    assert_eq!(COUNT, (2, 2));
+
+    let chunks = slice_chunks_exact!(&[1i32, 2, 3, 4, 5, 6, 7], 2);
+    assert_eq!(chunks.remainder(), &[7]);
+    assert_eq!(chunks.collect::<Vec<_>>(), [&[1, 2], &[3, 4], &[5, 6]]);
+
+    let mut chunks = slice_chunks_exact!(b"ab", 3);
+    assert_eq!(chunks.remainder(), b"ab");
+    assert_eq!(chunks.next(), None);
+}
+
+#[test]
+fn slice_is_uniform() {
+    const UNIFORM: bool = slice_is_uniform!(b"aaaa");
+    assert_eq!(UNIFORM, true);
+    const NON_UNIFORM: bool = slice_is_uniform!(&[1i32, 1, 2]);
+    assert_eq!(NON_UNIFORM, false);
+    const EMPTY: bool = slice_is_uniform!(&['a'; 0]);
+    assert_eq!(EMPTY, true);
+    const SINGLE: bool = slice_is_uniform!(&[false]);
+    assert_eq!(SINGLE, true);
+}
+
+#[test]
+fn slice_run_boundaries() {
+    const RUNS: ([usize; 6], usize) = slice_run_boundaries!(&[1i32, 1, 2, 2, 2, 3]);
+    assert_eq!(&RUNS.0[..RUNS.1], &[0, 2, 5]);
+    assert_eq!(RUNS, ([0, 2, 5, 0, 0, 0], 3));
+    const BYTES: ([usize; 4], usize) = slice_run_boundaries!(b"abba");
+    assert_eq!(BYTES, ([0, 1, 3, 0], 3));
+    const EMPTY: ([usize; 0], usize) = slice_run_boundaries!(&[false; 0]);
+    assert_eq!(EMPTY, ([], 0));
+}
+
+#[test]
+fn slice_is_repetition_of() {
+    const TILED: bool = slice_is_repetition_of!(b"abab", b"ab");
+    assert_eq!(TILED, true);
+    const PARTIAL: bool = slice_is_repetition_of!(b"ababa", b"ab");
+    assert_eq!(PARTIAL, false);
+    const MISMATCH: bool = slice_is_repetition_of!(&[1i32, 2, 1, 3], &[1, 2]);
+    assert_eq!(MISMATCH, false);
+    const STR: bool = slice_is_repetition_of!("✨✨✨", "✨");
+    assert_eq!(STR, true);
+    assert!(slice_is_repetition_of!(&[0u8; 0], b"ab"));
+    assert!(slice_is_repetition_of!(&[0u8; 0], &[0u8; 0]));
+    assert!(!slice_is_repetition_of!(b"a", &[0u8; 0]));
+}
+
+#[test]
+fn ops() {
+    use crate::ops;
+
+    const CMP_BYTES: Ordering = ops::cmp_bytes(b"abc", b"abd");
+    assert_eq!(CMP_BYTES, Ordering::Less);
+    const CMP_STR: Ordering = ops::cmp_str("b", "aa");
+    assert_eq!(CMP_STR, Ordering::Greater);
+    const EQ_BYTES: bool = ops::eq_bytes(b"abc", b"abc");
+    assert_eq!(EQ_BYTES, true);
+    const EQ_STR: bool = ops::eq_str("abc", "abd");
+    assert_eq!(EQ_STR, false);
+    const PREFIX_BYTES: Option<&[u8]> = ops::strip_prefix_bytes(b"key=value", b"key=");
+    assert_eq!(PREFIX_BYTES, Some(&b"value"[..]));
+    const PREFIX_STR: Option<&str> = ops::strip_prefix_str("key=value", "value");
+    assert_eq!(PREFIX_STR, None);
+    const SUFFIX_BYTES: Option<&[u8]> = ops::strip_suffix_bytes(b"file.rs", b".txt");
+    assert_eq!(SUFFIX_BYTES, None);
+    const SUFFIX_STR: Option<&str> = ops::strip_suffix_str("file.rs", ".rs");
+    assert_eq!(SUFFIX_STR, Some("file"));
+
+    let eq: fn(&str, &str) -> bool = ops::eq_str;
+    assert!(["a", "b"].iter().any(|s| eq(s, "b")));
+}
+
+#[test]
+fn slice_take_skip_while_eq() {
+    const TAKE: &[u8] = slice_take_while_eq!(b"   x", b' ');
+    assert_eq!(TAKE, b"   ");
+    const SKIP: &[u8] = slice_skip_while_eq!(b"   x", b' ');
+    assert_eq!(SKIP, b"x");
+    const TAKE_NONE: &[char] = slice_take_while_eq!(&['x', ' '], ' ');
+    assert_eq!(TAKE_NONE, &[]);
+    const SKIP_ALL: &[i32] = slice_skip_while_eq!(&[0i32, 0], 0);
+    assert_eq!(SKIP_ALL, &[]);
+}
+
+#[test]
+fn slice_starts_with_any() {
+    const SCHEME: Option<usize> = slice_starts_with_any!("https://", ["http://", "https://"]);
+    assert_eq!(SCHEME, Some(1));
+    const FIRST: Option<usize> = slice_starts_with_any!("http://x", ["http://", "http"]);
+    assert_eq!(FIRST, Some(0));
+    const NONE: Option<usize> = slice_starts_with_any!("ftp://", ["http://", "https://"]);
+    assert_eq!(NONE, None);
+    const BYTES: Option<usize> =
+        slice_starts_with_any!(b"\x89PNG".as_slice(), [b"GIF", b"\x89PNG"]);
+    assert_eq!(BYTES, Some(1));
+}
+
+#[test]
+fn slice_min_max() {
+    const MIN_MAX: Option<(&i32, &i32)> = slice_min_max!(&[3i32, -1, 7, 2, -1]);
+    assert_eq!(MIN_MAX, Some((&-1, &7)));
+    const SINGLE: Option<(&char, &char)> = slice_min_max!(&['x']);
+    assert_eq!(SINGLE, Some((&'x', &'x')));
+    const EMPTY: Option<(&u8, &u8)> = slice_min_max!(b"");
+    assert_eq!(EMPTY, None);
+
+    let values = [5u16, 1, 9, 1, 9];
+    let (min, max) = slice_min_max!(&values).unwrap();
+    assert!(core::ptr::eq(min, slice_min!(&values).unwrap()));
+    assert!(core::ptr::eq(max, slice_max!(&values).unwrap()));
+}
+
+#[test]
+fn slice_map() {
+    const ADD: [u8; 3] = slice_map_add!([1u8, 2, 3], 10);
+    assert_eq!(ADD, [11, 12, 13]);
+
+    const ADD_WRAPPING: [u8; 3] = slice_map_add!([1u8, 128, 255], 128);
+    assert_eq!(ADD_WRAPPING, [129, 0, 127]);
+
+    const ADD_REF: [u8; 3] = slice_map_add!(b"abc", 1);
+    assert_eq!(&ADD_REF, b"bcd");
+
+    const ADD_SIGNED: [i32; 2] = slice_map_add!([-1, i32::MAX], 1);
+    assert_eq!(ADD_SIGNED, [0, i32::MIN]);
+
+    const MUL: [u16; 3] = slice_map_mul!([1u16, 2, 3], 10);
+    assert_eq!(MUL, [10, 20, 30]);
+
+    const MUL_WRAPPING: [u8; 2] = slice_map_mul!(&[2u8, 129], 2);
+    assert_eq!(MUL_WRAPPING, [4, 2]);
+}
+
+#[test]
+fn slice_sort() {
+    const SORTED: [i32; 5] = slice_sort!([3i32, -1, 2, 0, 2]);
+    assert_eq!(SORTED, [-1, 0, 2, 2, 3]);
+
+    const SORTED_DESC: [i32; 3] = slice_sort_desc!([1i32, 3, 2]);
+    assert_eq!(SORTED_DESC, [3, 2, 1]);
+
+    const SORTED_BYTES: [u8; 5] = slice_sort!(b"hello");
+    assert_eq!(&SORTED_BYTES, b"ehllo");
+
+    const SORTED_DESC_CHARS: [char; 4] = slice_sort_desc!(['b', 'd', 'a', 'b']);
+    assert_eq!(SORTED_DESC_CHARS, ['d', 'b', 'b', 'a']);
+
+    const SORTED_EMPTY: [bool; 0] = slice_sort_desc!([false; 0]);
+    assert_eq!(SORTED_EMPTY, []);
+}
+
+#[test]
+fn ascii_case_bytes() {
+    const UPPER: [u8; 12] = slice_to_ascii_uppercase_bytes!(b"Content-Type");
+    assert_eq!(&UPPER, b"CONTENT-TYPE");
+
+    const LOWER: [u8; 12] = slice_to_ascii_lowercase_bytes!(b"Content-Type");
+    assert_eq!(&LOWER, b"content-type");
+
+    const UPPER_BINARY: [u8; 5] = slice_to_ascii_uppercase_bytes!([b'a', 0, 0x7f, 0xe1, 0xff]);
+    assert_eq!(UPPER_BINARY, [b'A', 0, 0x7f, 0xe1, 0xff]);
+
+    const LOWER_BINARY: [u8; 5] = slice_to_ascii_lowercase_bytes!([b'A', 0, 0x7f, 0xc1, 0xff]);
+    assert_eq!(LOWER_BINARY, [b'a', 0, 0x7f, 0xc1, 0xff]);
+}
+
+#[test]
+fn eq() {
+    const EMPTY: bool = slice_eq!("", "");
+    assert_eq!(EMPTY, true);
+
+    const EQ: bool = slice_eq!("hi", "hi");
+    assert_eq!(EQ, true);
+
+    const NEQ: bool = slice_eq!("hi", "ho");
+    assert_eq!(NEQ, false);
+
+    const NEQ2: bool = slice_eq!("hi", "hello");
+    assert_eq!(NEQ2, false);
+}
+
+#[test]
+fn slice_fast_eq() {
+    const EQ: bool = slice_fast_eq!(b"0123456789abcdef!", b"0123456789abcdef!");
+    assert_eq!(EQ, true);
+    const LAST_WORD: bool = slice_fast_eq!(b"0123456789abcdef", b"0123456789abcdeF");
+    assert_eq!(LAST_WORD, false);
+
+    let a: Vec<u8> = (0..=33).collect();
+    for len in 0..=33 {
+        let (a, mut b) = (&a[..len], a[..len].to_vec());
+        assert!(slice_fast_eq!(a, &b), "{len}");
+        assert!(!slice_fast_eq!(a, &a[..len.saturating_sub(1)]) || len == 0);
+        for i in 0..len {
+            b[i] ^= 0x80;
+            assert!(!slice_fast_eq!(a, &b), "{len} {i}");
+            b[i] ^= 0x80;
+        }
+    }
+}
+
+#[test]
+fn eq_length_mismatch() {
+    // reading a mutable static is a compile time error in a const, so this guards against
+    // any element being read
+    static mut GUARD: [u8; 4] = [0; 4];
+    const GUARDED: &[u8] = unsafe { &*core::ptr::addr_of!(GUARD) };
+    const SHORTER: bool = slice_eq!(GUARDED, [0u8; 3]);
+    assert_eq!(SHORTER, false);
+    const LONGER: bool = slice_eq!(&[0u8; 5] as &[u8], GUARDED);
+    assert_eq!(LONGER, false);
+    const ROWS: &[[u8; 4]] = core::slice::from_ref(unsafe { &*core::ptr::addr_of!(GUARD) });
+    const TABLE: bool = slice_eq!(ROWS, [[0u8; 4]; 2]);
+    assert_eq!(TABLE, false);
+    const STR: bool = slice_eq!("abc", unsafe { core::str::from_utf8_unchecked(GUARDED) });
+    assert_eq!(STR, false);
+}
+
+#[test]
+fn str_len() {
+    const ASCII_LEN: usize = str_byte_len!("abc");
+    assert_eq!(ASCII_LEN, 3);
+
+    const MULTIBYTE_LEN: usize = str_byte_len!("✨💖");
+    assert_eq!(MULTIBYTE_LEN, 7);
+
+    const EMPTY: bool = str_is_empty!("");
+    assert_eq!(EMPTY, true);
+
+    const NOT_EMPTY: bool = str_is_empty!("✨");
+    assert_eq!(NOT_EMPTY, false);
+}
+
+#[test]
+fn str_byte() {
+    const FIRST: Option<u8> = str_byte!("abc", 0);
+    assert_eq!(FIRST, Some(b'a'));
+
+    const LAST: Option<u8> = str_byte!("abc", 2);
+    assert_eq!(LAST, Some(b'c'));
+
+    const OUT_OF_RANGE: Option<u8> = str_byte!("abc", 3);
+    assert_eq!(OUT_OF_RANGE, None);
+
+    const MULTIBYTE: Option<u8> = str_byte!("✨", 1);
+    assert_eq!(MULTIBYTE, Some("✨".as_bytes()[1]));
+}
+
+#[test]
+fn str_find_char() {
+    const FIND: Option<usize> = str_find_char!("a✨b✨", '✨');
+    assert_eq!(FIND, Some(1));
+
+    const RFIND: Option<usize> = str_rfind_char!("a✨b✨", '✨');
+    assert_eq!(RFIND, Some(5));
+
+    const FIND_ASCII: Option<usize> = str_find_char!("💖a💖a", 'a');
+    assert_eq!(FIND_ASCII, Some(4));
+
+    const RFIND_ASCII: Option<usize> = str_rfind_char!("💖a💖a", 'a');
+    assert_eq!(RFIND_ASCII, Some(9));
+
+    const FIND_NONE: Option<usize> = str_find_char!("abc", '💖');
+    assert_eq!(FIND_NONE, None);
+
+    const RFIND_NONE: Option<usize> = str_rfind_char!("", 'a');
+    assert_eq!(RFIND_NONE, None);
+
+    let s = "\u{7f}\u{80}\u{7ff}\u{800}\u{ffff}\u{10000}\u{10ffff}";
+    for (i, ch) in s.char_indices() {
+        assert_eq!(str_find_char!(s, ch), Some(i));
+        assert_eq!(str_rfind_char!(s, ch), Some(i));
+    }
+}
+
+#[test]
+fn str_count_char() {
+    const COMMAS: usize = str_count_char!("a,b,c", ',');
+    assert_eq!(COMMAS, 2);
+
+    const SPARKLES: usize = str_count_char!("✨a✨💖✨", '✨');
+    assert_eq!(SPARKLES, 3);
+
+    const HEARTS: usize = str_count_char!("✨a✨💖✨", '💖');
+    assert_eq!(HEARTS, 1);
+
+    const NONE: usize = str_count_char!("abc", '✨');
+    assert_eq!(NONE, 0);
+
+    const EMPTY: usize = str_count_char!("", 'a');
+    assert_eq!(EMPTY, 0);
+}
+
+#[test]
+fn str_pad_end() {
+    const PADDED: [u8; 5] = str_pad_end_ascii!("hi", 5, '.');
+    assert_eq!(&PADDED, b"hi...");
+
+    const PADDED_MULTIBYTE: [u8; 5] = str_pad_end_ascii!("✨", 5, ' ');
+    assert_eq!(&PADDED_MULTIBYTE, "✨  ".as_bytes());
+
+    const PADDED_STR: StrBuf<5> = str_pad_end!("hi", 5, '.');
+    const STR: &str = PADDED_STR.as_str();
+    assert_eq!(STR, "hi...");
+    assert_eq!(&*PADDED_STR, "hi...");
+
+    const PADDED_EXACT: StrBuf<3> = str_pad_end!("✨", 3, '.');
+    assert_eq!(PADDED_EXACT.as_str(), "✨");
+}
+
+#[test]
+#[should_panic(expected = "fill character must be ascii")]
+fn str_pad_end_non_ascii_fill() {
+    let _ = str_pad_end_ascii!("hi", 5, '✨');
+}
+
+#[test]
+fn str_eq_any() {
+    const GET: bool = str_eq_any!("GET", ["GET", "POST", "PUT"]);
+    assert_eq!(GET, true);
+
+    const PUT: bool = str_eq_any!("PUT", ["GET", "POST", "PUT"]);
+    assert_eq!(PUT, true);
+
+    const DELETE: bool = str_eq_any!("DELETE", ["GET", "POST", "PUT"]);
+    assert_eq!(DELETE, false);
+
+    const PREFIX: bool = str_eq_any!("GE", ["GET"]);
+    assert_eq!(PREFIX, false);
+}
+
+#[test]
+fn assert_slice_eq() {
+    const TABLE: [u8; 3] = [1, 2, 3];
+    const _: () = const_assert_slice_eq!(&TABLE, &[1, 2, 3], "unexpected table");
+    const _: () = const_assert_slice_eq!("abc", "abc", "unexpected str");
+    const_assert_slice_eq!(b"", b"", "unexpected bytes");
+}
+
+#[test]
+#[should_panic(expected = "strings differ")]
+fn assert_slice_eq_fail() {
+    const_assert_slice_eq!("abc", "abd", "strings differ");
+}
+
+#[test]
+fn cmp() {
+    const CMP1: Ordering = slice_cmp!("hi", "hi");
+    assert_eq!(CMP1, Ordering::Equal);
+
+    const CMP2: Ordering = slice_cmp!("hi", "ho");
+    assert_eq!(CMP2, Ordering::Less);
+
+    const CMP3: Ordering = slice_cmp!("ho", "hi");
+    assert_eq!(CMP3, Ordering::Greater);
+
+    const CMP4: Ordering = slice_cmp!("h", "hi");
+    assert_eq!(CMP4, Ordering::Less);
+
+    const CMP5: Ordering = slice_cmp!("hi", "h");
+    assert_eq!(CMP5, Ordering::Greater);
+}
+
+#[test]
+fn cmp_lexicographic() {
+    const CMP1: Ordering = slice_cmp!("b", "ab");
+    assert_eq!(CMP1, Ordering::Greater);
+
+    const CMP2: Ordering = slice_cmp!("ab", "b");
+    assert_eq!(CMP2, Ordering::Less);
+
+    const CMP3: Ordering = slice_cmp!(&[2u8], &[1u8, 5]);
+    assert_eq!(CMP3, Ordering::Greater);
+}
+
+#[test]
+fn cmp_signed() {
+    const CMP1: Ordering = slice_cmp!(&[-1i8], &[0i8]);
+    assert_eq!(CMP1, Ordering::Less);
+
+    const CMP2: Ordering = slice_cmp!(&[i8::MIN], &[i8::MAX]);
+    assert_eq!(CMP2, Ordering::Less);
+
+    const CMP3: Ordering = slice_cmp!(&[1i8], &[-1i8, 5]);
+    assert_eq!(CMP3, Ordering::Greater);
+
+    const CMP4: Ordering = slice_cmp!(&[-1i64, 5], &[-1i64]);
+    assert_eq!(CMP4, Ordering::Greater);
+
+    const CMP5: Ordering = slice_cmp!(&[-5i32, 1], &[-4i32]);
+    assert_eq!(CMP5, Ordering::Less);
+
+    let cases: [(&[i8], &[i8]); 7] = [
+        (&[], &[]),
+        (&[], &[-1]),
+        (&[-1], &[0]),
+        (&[-128], &[127]),
+        (&[0, -1], &[0]),
+        (&[5], &[-5, 5]),
+        (&[-3, -2, -1], &[-3, -2, -1]),
+    ];
+    for (a, b) in cases {
+        assert_eq!(slice_cmp!(a, b), a.cmp(b));
+        assert_eq!(slice_cmp!(b, a), b.cmp(a));
+    }
+}
+
+#[test]
+fn cmp_bool_char() {
+    const BOOL: Ordering = slice_cmp!(&[false], &[true]);
+    assert_eq!(BOOL, Ordering::Less);
+
+    const BOOL_PREFIX: Ordering = slice_cmp!(&[true], &[false, false]);
+    assert_eq!(BOOL_PREFIX, Ordering::Greater);
+
+    const BOOL_EQ: bool = slice_eq!(&[true, false], &[true, false]);
+    assert_eq!(BOOL_EQ, true);
+
+    const CHAR: Ordering = slice_cmp!(&['a'], &['b']);
+    assert_eq!(CHAR, Ordering::Less);
+
+    const CHAR_SCALAR: Ordering = slice_cmp!(&['💖'], &['✨']);
+    assert_eq!(CHAR_SCALAR, Ordering::Greater);
+
+    const CHAR_PREFIX: Ordering = slice_cmp!(&['b'], &['a', 'z']);
+    assert_eq!(CHAR_PREFIX, Ordering::Greater);
+
+    const CHAR_NEQ: bool = slice_eq!(&['a', 'b'], &['a', 'b', 'c']);
+    assert_eq!(CHAR_NEQ, false);
+
+    let bools: [&[bool]; 4] = [&[], &[false], &[false, true], &[true]];
+    let chars: [&[char]; 4] = [&[], &['a', '💖'], &['b'], &['✨', 'a']];
+    for a in bools {
+        for b in bools {
+            assert_eq!(slice_cmp!(a, b), a.cmp(b));
+        }
+    }
+    for a in chars {
+        for b in chars {
+            assert_eq!(slice_cmp!(a, b), a.cmp(b));
+        }
+    }
+}
+
+#[test]
+fn cmp_tuple() {
+    const CMP1: Ordering = slice_cmp_tuple!(("key", b"\x01"), ("key", b"\x01"));
+    assert_eq!(CMP1, Ordering::Equal);
+
+    const CMP2: Ordering = slice_cmp_tuple!(("key", b"\x01"), ("key", b"\x02"));
+    assert_eq!(CMP2, Ordering::Less);
+
+    const CMP3: Ordering = slice_cmp_tuple!(("key", b"\x02"), ("key", b"\x01"));
+    assert_eq!(CMP3, Ordering::Greater);
+
+    const CMP4: Ordering = slice_cmp_tuple!(("a", b"\x02"), ("b", b"\x01"));
+    assert_eq!(CMP4, Ordering::Less);
+
+    const CMP5: Ordering = slice_cmp_tuple!(("b", b"\x01"), ("a", b"\x02"));
+    assert_eq!(CMP5, Ordering::Greater);
+}
+
+#[test]
+fn eq_prefix() {
+    const EQ: bool = slice_eq_prefix!(b"abcde", b"abcxy", 3);
+    assert_eq!(EQ, true);
+
+    const NEQ: bool = slice_eq_prefix!(b"abcde", b"abxyz", 3);
+    assert_eq!(NEQ, false);
+
+    const TOO_SHORT: bool = slice_eq_prefix!(b"abcde", b"ab", 3);
+    assert_eq!(TOO_SHORT, false);
+
+    const WHOLE: bool = slice_eq_prefix!("abc", "abc", 3);
+    assert_eq!(WHOLE, true);
+
+    const EMPTY: bool = slice_eq_prefix!("", "abc", 0);
+    assert_eq!(EMPTY, true);
+
+    const SPLITS_CODEPOINT: bool = slice_eq_prefix!("✨a", "✨b", 2);
+    assert_eq!(SPLITS_CODEPOINT, false);
+}
+
+#[test]
+fn contains_any() {
+    const CONTAINS: bool = slice_contains_any!(b"a,b;c", [b',', b';']);
+    assert_eq!(CONTAINS, true);
+
+    const CONTAINS_LAST: bool = slice_contains_any!(b"a;b", [b',', b';']);
+    assert_eq!(CONTAINS_LAST, true);
+
+    const NOT_CONTAINS: bool = slice_contains_any!(b"abc", [b',', b';']);
+    assert_eq!(NOT_CONTAINS, false);
+
+    const CONTAINS_INT: bool = slice_contains_any!(&[1i32, -2, 3], [-2]);
+    assert_eq!(CONTAINS_INT, true);
+
+    const EMPTY: bool = slice_contains_any!(b"".as_slice(), [0, 1, 2]);
+    assert_eq!(EMPTY, false);
+}
+
+#[test]
+fn split_once() {
+    const SPLIT: Option<(&[u8], &[u8])> = slice_split_once!(b"a.b.c", b'.');
+    assert_eq!(SPLIT, Some((&b"a"[..], &b"b.c"[..])));
+
+    const RSPLIT: Option<(&[u8], &[u8])> = slice_rsplit_once!(b"a.b.c", b'.');
+    assert_eq!(RSPLIT, Some((&b"a.b"[..], &b"c"[..])));
+
+    const RSPLIT_EDGE: Option<(&[u8], &[u8])> = slice_rsplit_once!(b"abc.", b'.');
+    assert_eq!(RSPLIT_EDGE, Some((&b"abc"[..], &b""[..])));
+
+    const RSPLIT_NONE: Option<(&[u8], &[u8])> = slice_rsplit_once!(b"abc".as_slice(), b'.');
+    assert_eq!(RSPLIT_NONE, None);
+
+    const SPLIT_CHARS: Option<(&[char], &[char])> = slice_split_once!(&['x', '=', 'y'], '=');
+    assert_eq!(SPLIT_CHARS, Some((&['x'][..], &['y'][..])));
+}
+
+#[test]
+fn split_inclusive_once() {
+    const SPLIT: Option<(&[u8], &[u8])> = slice_split_inclusive_once!(b"ab\ncd", b'\n');
+    assert_eq!(SPLIT, Some((&b"ab\n"[..], &b"cd"[..])));
+    const END: Option<(&[u8], &[u8])> = slice_split_inclusive_once!(b"ab\n\n", b'\n');
+    assert_eq!(END, Some((&b"ab\n"[..], &b"\n"[..])));
+    const NONE: Option<(&[char], &[char])> = slice_split_inclusive_once!(&['a', 'b'], ';');
+    assert_eq!(NONE, None);
+}
+
+#[test]
+fn prefix() {
+    const STARTS_WITH: bool = slice_starts_with!("abcde", "ab");
+    assert_eq!(STARTS_WITH, true);
+
+    const NOT_STARTS_WITH: bool = slice_starts_with!("abcde", "aba");
+    assert_eq!(NOT_STARTS_WITH, false);
+
+    const STRIPPED: Option<&str> = slice_strip_prefix!("abcde", "abc");
+    assert_eq!(STRIPPED, Some("de"));
+
+    const NOT_STRIPPED: Option<&str> = slice_strip_prefix!("abcde", "ace");
+    assert_eq!(NOT_STRIPPED, None);
+
+    const BYTES: Option<&[u8]> = slice_strip_prefix!(b"abcde", b"ab");
+    assert_eq!(BYTES, Some(&b"cde"[..]));
+
+    const INTS: Option<&[i32]> = slice_strip_prefix!(&[1i32, 2, 3], &[1i32, 2]);
+    assert_eq!(INTS, Some(&[3][..]));
+
+    const TOO_LONG: Option<&[i32]> = slice_strip_prefix!(&[1i32], &[1i32, 2]);
+    assert_eq!(TOO_LONG, None);
+}
+
+#[test]
+fn suffix() {
+    const ENDS_WITH: bool = slice_ends_with!("abcde", "de");
+    assert_eq!(ENDS_WITH, true);
+
+    const NOT_ENDS_WITH: bool = slice_ends_with!("abcde", "ee");
+    assert_eq!(NOT_ENDS_WITH, false);
+
+    const STRIPPED: Option<&str> = slice_strip_suffix!("abcde", "cde");
+    assert_eq!(STRIPPED, Some("ab"));
+
+    const NOT_STRIPPED: Option<&str> = slice_strip_suffix!("abcde", "cdf");
+    assert_eq!(NOT_STRIPPED, None);
+
+    const BYTES: Option<&[u8]> = slice_strip_suffix!(b"abcde", b"de");
+    assert_eq!(BYTES, Some(&b"abc"[..]));
+
+    const INTS: Option<&[i32]> = slice_strip_suffix!(&[1i32, 2, 3], &[2i32, 3]);
+    assert_eq!(INTS, Some(&[1][..]));
+
+    const TOO_LONG: Option<&[i32]> = slice_strip_suffix!(&[3i32], &[2i32, 3]);
+    assert_eq!(TOO_LONG, None);
+}
+
+#[test]
+fn slice_ref_ord() {
+    let mut strs: Vec<SliceRef<str>> = ["b", "c", "a", "", "b"].into_iter().map(SliceRef).collect();
+    strs.sort();
+    let sorted: Vec<&str> = strs.iter().map(|s| s.0).collect();
+    assert_eq!(sorted, ["", "a", "b", "b", "c"]);
+
+    assert!(SliceRef(&b"abc"[..]) == SliceRef(&b"abc"[..]));
+    assert!(SliceRef(&b"abc"[..]) < SliceRef(&b"abd"[..]));
+    assert!(SliceRef(b"abc") != SliceRef(b"abd"));
+}
+
+#[test]
+fn slice_ref_identity() {
+    let bytes: Vec<u8> = (0..=255).cycle().take(1 << 10).collect();
+    let copy = bytes.clone();
+    assert!(SliceRef(&bytes[..]) == SliceRef(&bytes[..]));
+    assert_eq!(
+        Ord::cmp(&SliceRef(&bytes[..]), &SliceRef(&bytes[..])),
+        Ordering::Equal
+    );
+    assert!(SliceRef(&bytes[..]) == SliceRef(&copy[..]));
+
+    // same pointer but different length isn't the same slice
+    assert!(SliceRef(&bytes[..1]) < SliceRef(&bytes[..2]));
+    assert!(SliceRef(&bytes[..2]) > SliceRef(&bytes[..1]));
+
+    let s = "const it";
+    assert!(SliceRef(s) == SliceRef(s));
+    assert!(SliceRef(&s[..5]) < SliceRef(s));
+    assert_eq!(slice_cmp!(s, s), Ordering::Equal);
+    assert_eq!(slice_eq!(&bytes[..], &bytes[..]), true);
+}
+
+#[test]
+fn ascii_digits() {
+    const HEX_F: Option<u8> = ascii_to_digit!(b'f', 16);
+    assert_eq!(HEX_F, Some(15));
+
+    const HEX_UPPER_F: Option<u8> = ascii_to_digit!(b'F', 16);
+    assert_eq!(HEX_UPPER_F, Some(15));
+
+    const HEX_G: Option<u8> = ascii_to_digit!(b'g', 16);
+    assert_eq!(HEX_G, None);
+
+    const DEC_9: Option<u8> = ascii_to_digit!(b'9', 10);
+    assert_eq!(DEC_9, Some(9));
+
+    const BIN_2: Option<u8> = ascii_to_digit!(b'2', 2);
+    assert_eq!(BIN_2, None);
+
+    const NOT_DIGIT: Option<u8> = ascii_to_digit!(b' ', 36);
+    assert_eq!(NOT_DIGIT, None);
+
+    const ASCII_F: Option<u8> = digit_to_ascii!(15, 16);
+    assert_eq!(ASCII_F, Some(b'f'));
+
+    const ASCII_7: Option<u8> = digit_to_ascii!(7, 8);
+    assert_eq!(ASCII_7, Some(b'7'));
+
+    const ASCII_OUT_OF_RANGE: Option<u8> = digit_to_ascii!(8, 8);
+    assert_eq!(ASCII_OUT_OF_RANGE, None);
+
+    for radix in 2..=36 {
+        for value in 0..radix as u8 {
+            assert_eq!(
+                ascii_to_digit!(digit_to_ascii!(value, radix).unwrap(), radix),
+                Some(value)
+            );
+        }
+    }
+}
+
+#[test]
+fn count_leading_trailing() {
+    const LEADING: usize = slice_count_leading!(b"\0\0abc", 0);
+    assert_eq!(LEADING, 2);
+
+    const LEADING_NONE: usize = slice_count_leading!(b"abc\0", 0);
+    assert_eq!(LEADING_NONE, 0);
+
+    const LEADING_ALL: usize = slice_count_leading!(&[7i32, 7, 7], 7);
+    assert_eq!(LEADING_ALL, 3);
+
+    const TRAILING: usize = slice_count_trailing!(b"abc\0\0", 0);
+    assert_eq!(TRAILING, 2);
+
+    const TRAILING_ALL: usize = slice_count_trailing!(b"aaaa".as_slice(), b'a');
+    assert_eq!(TRAILING_ALL, 4);
+
+    const TRAILING_EMPTY: usize = slice_count_trailing!(&['a'; 0], 'a');
+    assert_eq!(TRAILING_EMPTY, 0);
+}
+
+#[test]
+fn palindrome() {
+    const ABBA: bool = slice_is_palindrome!(b"abba");
+    assert_eq!(ABBA, true);
+
+    const ABCBA: bool = slice_is_palindrome!(b"abcba".as_slice());
+    assert_eq!(ABCBA, true);
+
+    const ABC: bool = slice_is_palindrome!(b"abc");
+    assert_eq!(ABC, false);
+
+    const EMPTY: bool = slice_is_palindrome!(&[0u32; 0]);
+    assert_eq!(EMPTY, true);
+
+    const STR: bool = slice_is_palindrome!("racecar");
+    assert_eq!(STR, true);
+
+    const STR_MULTIBYTE: bool = slice_is_palindrome!("✨a✨");
+    assert_eq!(STR_MULTIBYTE, false);
+}
+
+#[test]
+fn min_max() {
+    const MIN: Option<&i32> = slice_min!(&[3i32, -1, 2]);
+    assert_eq!(MIN, Some(&-1));
+
+    const MAX: Option<&u8> = slice_max!(b"hello");
+    assert_eq!(MAX, Some(&b'o'));
+
+    const MIN_EMPTY: Option<&u8> = slice_min!(b"");
+    assert_eq!(MIN_EMPTY, None);
+
+    const MIN_INDEX: Option<usize> = slice_min_index!(&[3i32, -1, 2, -1]);
+    assert_eq!(MIN_INDEX, Some(1));
+
+    const MAX_INDEX: Option<usize> = slice_max_index!(&[3i32, -1, 3]);
+    assert_eq!(MAX_INDEX, Some(0));
+
+    const MAX_INDEX_CHARS: Option<usize> = slice_max_index!(['a', 'c', 'b', 'c'].as_slice());
+    assert_eq!(MAX_INDEX_CHARS, Some(1));
+
+    const MIN_INDEX_EMPTY: Option<usize> = slice_min_index!(&[0u64; 0]);
+    assert_eq!(MIN_INDEX_EMPTY, None);
+
+    const MAX_INDEX_EMPTY: Option<usize> = slice_max_index!(b"".as_slice());
+    assert_eq!(MAX_INDEX_EMPTY, None);
+}
+
+#[test]
+fn partition_counts() {
+    const COUNTS: (usize, usize, usize) = slice_partition_counts!(&[1i32, 2, 2, 3], 2);
+    assert_eq!(COUNTS, (1, 2, 1));
+
+    const COUNTS_BYTES: (usize, usize, usize) = slice_partition_counts!(b"hello", b'l');
+    assert_eq!(COUNTS_BYTES, (2, 2, 1));
+
+    const COUNTS_NO_PIVOT: (usize, usize, usize) = slice_partition_counts!(&[5u16, 1, 9], 4);
+    assert_eq!(COUNTS_NO_PIVOT, (1, 0, 2));
+
+    const COUNTS_EMPTY: (usize, usize, usize) = slice_partition_counts!(b"".as_slice(), 0);
+    assert_eq!(COUNTS_EMPTY, (0, 0, 0));
+}
+
+#[test]
+fn split_whitespace() {
+    const fn nth_word(s: &str, n: usize) -> Option<&str> {
+        let mut words = str_split_whitespace!(s);
+        let mut i = 0;
+        while i < n {
+            if words.next().is_none() {
+                return None;
+            }
+            i += 1;
+        }
+        words.next()
+    }
+
+    const FIRST: Option<&str> = nth_word("  a  b c ", 0);
+    assert_eq!(FIRST, Some("a"));
+
+    const SECOND: Option<&str> = nth_word("  a  b c ", 1);
+    assert_eq!(SECOND, Some("b"));
+
+    const THIRD: Option<&str> = nth_word("  a  b c ", 2);
+    assert_eq!(THIRD, Some("c"));
+
+    const FOURTH: Option<&str> = nth_word("  a  b c ", 3);
+    assert_eq!(FOURTH, None);
+
+    let words: Vec<&str> = str_split_whitespace!("\t✨ b\r\n💖c ").collect();
+    assert_eq!(
+        words,
+        "\t✨ b\r\n💖c "
+            .split_ascii_whitespace()
+            .collect::<Vec<_>>()
+    );
+
+    assert_eq!(str_split_whitespace!(" \n ").next(), None);
+}
+
+#[test]
+fn to_bytes() {
+    const LE: [u8; 4] = slice_to_le_bytes!(&[0x01020304u32]);
+    assert_eq!(LE, [0x04, 0x03, 0x02, 0x01]);
+
+    const BE: [u8; 4] = slice_to_be_bytes!(&[0x01020304u32]);
+    assert_eq!(BE, [0x01, 0x02, 0x03, 0x04]);
+
+    const WORDS: &[u16] = &[0x0102, 0x0304];
+    const LE_SLICE: [u8; 4] = slice_to_le_bytes!(WORDS);
+    assert_eq!(LE_SLICE, [0x02, 0x01, 0x04, 0x03]);
+
+    const BE_SIGNED: [u8; 2] = slice_to_be_bytes!(&[-1i8, 2]);
+    assert_eq!(BE_SIGNED, [0xff, 0x02]);
+
+    const LE_EMPTY: [u8; 0] = slice_to_le_bytes!(&[0u64; 0]);
+    assert_eq!(LE_EMPTY, []);
+}
+
+#[test]
+fn from_bytes() {
+    const WORDS: &[u32] = &[0x01020304, 0xdeadbeef];
+    const LE: [u8; 8] = slice_to_le_bytes!(WORDS);
+    const BE: [u8; 8] = slice_to_be_bytes!(WORDS);
+
+    const FROM_LE: Option<[u32; 2]> = bytes_from_le!(u32, &LE);
+    assert_eq!(FROM_LE.as_ref().map(|v| &v[..]), Some(WORDS));
+
+    const FROM_BE: Option<[u32; 2]> = bytes_from_be!(u32, &BE);
+    assert_eq!(FROM_BE.as_ref().map(|v| &v[..]), Some(WORDS));
+
+    const HALVES: &[u16] = &[0x0102, 0xfffe, 0];
+    const HALVES_LE: [u8; 6] = slice_to_le_bytes!(HALVES);
+    const FROM_HALVES_LE: Option<[u16; 3]> = bytes_from_le!(u16, &HALVES_LE);
+    assert_eq!(FROM_HALVES_LE, Some([0x0102, 0xfffe, 0]));
+
+    const FROM_SIGNED_BE: Option<[i16; 1]> = bytes_from_be!(i16, b"\xff\xfe");
+    assert_eq!(FROM_SIGNED_BE, Some([-2]));
+
+    const BAD_LEN: Option<[u32; 1]> = bytes_from_le!(u32, b"\x01\x02\x03\x04\x05");
+    assert_eq!(BAD_LEN, None);
+}
+
+#[test]
+fn trim_matches() {
+    const TRIM_END: &[u8] = slice_trim_end_matches!(b"abc\0\0", 0);
+    assert_eq!(TRIM_END, b"abc");
+
+    const TRIM_START: &[u8] = slice_trim_start_matches!(b"\0\0abc\0", 0);
+    assert_eq!(TRIM_START, b"abc\0");
+
+    const TRIM_NOTHING: &[u8] = slice_trim_end_matches!(b"abc".as_slice(), 0);
+    assert_eq!(TRIM_NOTHING, b"abc");
+
+    const TRIM_ALL: &[u16] = slice_trim_start_matches!(&[1u16, 1, 1], 1);
+    assert_eq!(TRIM_ALL, &[]);
+
+    const TRIM_ALL_END: &[char] = slice_trim_end_matches!(&[' ', ' '], ' ');
+    assert_eq!(TRIM_ALL_END, &[]);
 }
@@ -0,0 +1,338 @@
+use core::{fmt, ops::Deref, str};
+
+/// A `str` stored in a fixed size byte array. This is returned by macros that build strings
+/// at compile time. Use [`as_str`](StrBuf::as_str) to get the string; to use it in a const
+/// context, store the `StrBuf` in a `const` item first.
+///
+/// ```rust
+/// # use const_it::{str_pad_end, StrBuf};
+/// const PADDED: StrBuf<5> = str_pad_end!("hi", 5, '.');
+/// const STR: &str = PADDED.as_str(); // "hi..."
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StrBuf<const N: usize>([u8; N]);
+
+impl<const N: usize> StrBuf<N> {
+    /// Create a new `StrBuf` from UTF-8 bytes, or return an error if the bytes aren't valid UTF-8
+    pub const fn from_utf8(bytes: [u8; N]) -> Result<Self, str::Utf8Error> {
+        match str::from_utf8(&bytes) {
+            Ok(_) => Ok(Self(bytes)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Create a new `StrBuf` from UTF-8 bytes without checking that they're valid.
+    ///
+    /// # Safety
+    /// The bytes must be valid UTF-8.
+    pub const unsafe fn from_utf8_unchecked(bytes: [u8; N]) -> Self {
+        Self(bytes)
+    }
+
+    /// Get the string
+    pub const fn as_str(&self) -> &str {
+        unsafe {
+            // safety: the bytes are valid utf-8
+            str::from_utf8_unchecked(&self.0)
+        }
+    }
+
+    /// Get the UTF-8 bytes of the string
+    pub const fn as_bytes(&self) -> &[u8; N] {
+        &self.0
+    }
+
+    /// Get the UTF-8 bytes of the string
+    pub const fn into_bytes(self) -> [u8; N] {
+        self.0
+    }
+}
+
+impl<const N: usize> Deref for StrBuf<N> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> fmt::Debug for StrBuf<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize> fmt::Display for StrBuf<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Pad the bytes of `s` to `N` bytes with the ASCII character `fill`.
+/// Panics if `fill` isn't ASCII or `s` is longer than `N` bytes.
+pub const fn pad_end_ascii<const N: usize>(s: &str, fill: char) -> [u8; N] {
+    assert!(fill.is_ascii(), "fill character must be ascii");
+    crate::array::pad_end(s.as_bytes(), fill as u8)
+}
+
+/// Pad `s` to `N` bytes with the ASCII character `fill`.
+/// Panics if `fill` isn't ASCII or `s` is longer than `N` bytes.
+pub const fn pad_end_str<const N: usize>(s: &str, fill: char) -> StrBuf<N> {
+    let bytes = pad_end_ascii(s, fill);
+    unsafe {
+        // safety: a valid str followed by ascii characters is valid utf-8
+        StrBuf::from_utf8_unchecked(bytes)
+    }
+}
+
+/// Concatenate `parts` into a string of `N` bytes.
+/// Panics if the total length of the parts isn't `N` bytes.
+pub const fn concat_str<const N: usize>(parts: &[&str]) -> StrBuf<N> {
+    join_str(parts, "")
+}
+
+/// Concatenate `parts` with `sep` between each part into a string of `N` bytes.
+/// Panics if the total length of the parts and separators isn't `N` bytes.
+pub const fn join_str<const N: usize>(parts: &[&str], sep: &str) -> StrBuf<N> {
+    let sep = sep.as_bytes();
+    let mut out = [0; N];
+    let mut len = 0;
+    let mut i = 0;
+    while i < parts.len() {
+        let part = parts[i].as_bytes();
+        let sep_len = if i == 0 { 0 } else { sep.len() };
+        assert!(
+            part.len() + sep_len <= N - len,
+            "total length of strings is longer than the output"
+        );
+        let mut j = 0;
+        while j < sep_len {
+            out[len] = sep[j];
+            len += 1;
+            j += 1;
+        }
+        let mut j = 0;
+        while j < part.len() {
+            out[len] = part[j];
+            len += 1;
+            j += 1;
+        }
+        i += 1;
+    }
+    assert!(
+        len == N,
+        "total length of strings is shorter than the output"
+    );
+    unsafe {
+        // safety: a concatenation of valid strs is valid utf-8
+        StrBuf::from_utf8_unchecked(out)
+    }
+}
+
+/// Reverse the bytes of the ASCII string `s`, or return an error if it isn't ASCII.
+/// Panics if `s` isn't `N` bytes long.
+pub const fn try_reverse_ascii<const N: usize>(s: &str) -> Result<[u8; N], &'static str> {
+    assert!(
+        s.len() == N,
+        "output length must be the length of the string"
+    );
+    if !s.is_ascii() {
+        return Err("string isn't ascii");
+    }
+    let bytes = s.as_bytes();
+    let mut out = [0; N];
+    let mut i = 0;
+    while i < N {
+        out[i] = bytes[N - 1 - i];
+        i += 1;
+    }
+    Ok(out)
+}
+
+/// Reverse the bytes of the ASCII string `s`. Panics if it isn't ASCII or isn't `N` bytes long.
+pub const fn reverse_ascii<const N: usize>(s: &str) -> [u8; N] {
+    expect_ok!(try_reverse_ascii(s))
+}
+
+/// Copy the bytes of `s` with the first byte converted to ASCII uppercase.
+/// Panics if `s` isn't `N` bytes long.
+pub const fn capitalize_ascii<const N: usize>(s: &str) -> [u8; N] {
+    assert!(
+        s.len() == N,
+        "output length must be the length of the string"
+    );
+    let bytes = s.as_bytes();
+    let mut out = [0; N];
+    let mut i = 0;
+    while i < N {
+        out[i] = bytes[i];
+        i += 1;
+    }
+    if N != 0 {
+        out[0] = out[0].to_ascii_uppercase();
+    }
+    out
+}
+
+/// Decode the `char` starting at byte index `i` of `s`, returning it along with the byte
+/// index of the next `char`. `i` must be on a codepoint boundary and less than `s.len()`.
+pub const fn decode_char(s: &str, i: usize) -> (char, usize) {
+    let bytes = s.as_bytes();
+    let b0 = bytes[i] as u32;
+    let (code, len) = if b0 < 0x80 {
+        (b0, 1)
+    } else if b0 < 0xe0 {
+        ((b0 & 0x1f) << 6 | (bytes[i + 1] as u32 & 0x3f), 2)
+    } else if b0 < 0xf0 {
+        (
+            (b0 & 0x0f) << 12 | (bytes[i + 1] as u32 & 0x3f) << 6 | (bytes[i + 2] as u32 & 0x3f),
+            3,
+        )
+    } else {
+        (
+            (b0 & 0x07) << 18
+                | (bytes[i + 1] as u32 & 0x3f) << 12
+                | (bytes[i + 2] as u32 & 0x3f) << 6
+                | (bytes[i + 3] as u32 & 0x3f),
+            4,
+        )
+    };
+    (
+        unsafe {
+            // safety: the str is valid utf-8, so this is a valid unicode scalar value
+            char::from_u32_unchecked(code)
+        },
+        i + len,
+    )
+}
+
+/// Get the byte index of the start of the `char` that ends at byte index `end` of `s`.
+/// `end` must be on a codepoint boundary and greater than zero.
+pub const fn prev_char_start(s: &str, end: usize) -> usize {
+    let bytes = s.as_bytes();
+    let mut i = end - 1;
+    while bytes[i] & 0xc0 == 0x80 {
+        i -= 1;
+    }
+    i
+}
+
+/// Get the byte index of the first occurrence of `ch` in `s`
+pub const fn find_char(s: &str, ch: char) -> Option<usize> {
+    let mut i = 0;
+    while i < s.len() {
+        let (c, next) = decode_char(s, i);
+        if c == ch {
+            return Some(i);
+        }
+        i = next;
+    }
+    None
+}
+
+/// Get the byte index of the last occurrence of `ch` in `s`
+pub const fn rfind_char(s: &str, ch: char) -> Option<usize> {
+    let mut end = s.len();
+    while end > 0 {
+        let start = prev_char_start(s, end);
+        if decode_char(s, start).0 == ch {
+            return Some(start);
+        }
+        end = start;
+    }
+    None
+}
+
+/// Count the number of occurrences of `ch` in `s`
+pub const fn count_char(s: &str, ch: char) -> usize {
+    let mut count = 0;
+    let mut i = 0;
+    while i < s.len() {
+        let (c, next) = decode_char(s, i);
+        if c == ch {
+            count += 1;
+        }
+        i = next;
+    }
+    count
+}
+
+/// Count the number of lines in `s`, like `s.lines().count()`
+pub const fn lines_count(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    let mut count = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'\n' {
+            count += 1;
+        }
+        i += 1;
+    }
+    if !bytes.is_empty() && bytes[bytes.len() - 1] != b'\n' {
+        count += 1;
+    }
+    count
+}
+
+/// Check if `s` is a non-empty string of ASCII alphanumerics and `_` that doesn't start with
+/// a digit
+pub const fn is_ascii_identifier(s: &str) -> bool {
+    let bytes = s.as_bytes();
+    if bytes.is_empty() || bytes[0].is_ascii_digit() {
+        return false;
+    }
+    let mut i = 0;
+    while i < bytes.len() {
+        if !(bytes[i].is_ascii_alphanumeric() || bytes[i] == b'_') {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Get the `n`th line of `s`, like `s.lines().nth(n)`
+pub const fn line_at(s: &str, n: usize) -> Option<&str> {
+    let bytes = s.as_bytes();
+    let mut line = 0;
+    let mut start = 0;
+    while start < bytes.len() {
+        let mut end = start;
+        while end < bytes.len() && bytes[end] != b'\n' {
+            end += 1;
+        }
+        if line == n {
+            let mut line_end = end;
+            if end < bytes.len() && end > start && bytes[end - 1] == b'\r' {
+                line_end -= 1;
+            }
+            let (head, _) = bytes.split_at(line_end);
+            let (_, line) = head.split_at(start);
+            return Some(unsafe {
+                // safety: line breaks are always on a codepoint boundary
+                str::from_utf8_unchecked(line)
+            });
+        }
+        line += 1;
+        start = end + 1;
+    }
+    None
+}
+
+/// Check if the `char`s of `s` are equal to `chars`
+pub const fn chars_eq(s: &str, chars: &[char]) -> bool {
+    let mut i = 0;
+    let mut j = 0;
+    while i < s.len() {
+        if j == chars.len() {
+            return false;
+        }
+        let (c, next) = decode_char(s, i);
+        if c != chars[j] {
+            return false;
+        }
+        i = next;
+        j += 1;
+    }
+    j == chars.len()
+}
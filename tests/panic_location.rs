@@ -0,0 +1,43 @@
+//! Checks that panics from `slice!` are reported at the caller's location.
+//!
+//! This installs a panic hook, so it lives in its own test binary with a single test to
+//! keep it from racing with other tests that panic.
+
+use const_it::{slice, slice_split_at};
+use std::{cell::RefCell, panic};
+
+/// Assert that evaluating the expression panics with a location in this file
+macro_rules! assert_panics_here {
+    ($expr:expr) => {{
+        let result = panic::catch_unwind(|| {
+            let _ = $expr;
+        });
+        assert!(result.is_err(), "expression didn't panic");
+        assert_eq!(
+            FILE.with(|f| f.borrow_mut().take()).as_deref(),
+            Some(file!())
+        );
+    }};
+}
+
+thread_local! {
+    static FILE: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+#[test]
+fn slice_panic_location() {
+    panic::set_hook(Box::new(|info| {
+        let file = info.location().map(|location| location.file().into());
+        FILE.with(|f| *f.borrow_mut() = file);
+    }));
+
+    let s = "✨";
+    let bytes: &[u8] = b"abc";
+    let end = 4;
+    assert_panics_here!(slice!(bytes, ..end));
+    assert_panics_here!(slice!(bytes, end));
+    assert_panics_here!(slice!(s, 1..));
+    assert_panics_here!(slice!(s, &(1..)));
+    assert_panics_here!(slice_split_at!(bytes, end));
+    assert_panics_here!(slice_split_at!(s, 1));
+}